@@ -0,0 +1,125 @@
+//! Exercise 1.1: Basic Async/Await
+//! 
+//! Time to get our feet wet with async/await syntax! This is where the fun begins.
+//! 
+//! ## Here's what we'll figure out
+//! 
+//! - How to convert regular functions to async ones
+//! - The difference between doing things one-at-a-time vs all-at-once
+//! - Why async code can be so much faster
+//! 
+//! ## Quick context
+//! 
+//! From Chapter 1 of the Rust Async Book:
+//! 
+//! > Async code allows us to write programs that can do multiple things at once.
+//! > Traditional synchronous code executes one operation at a time, blocking until
+//! > each completes. Async code can start multiple operations and switch between
+//! > them as they wait for external resources.
+//! 
+//! The key insight is that many programs spend time waiting - for network requests,
+//! disk I/O, or timers. Async programming lets us use that waiting time productively.
+//! Pretty neat, right?
+//! 
+//! ## Let's dive in
+//! 
+//! Fill in the gaps below to convert synchronous functions to async
+//! and implement concurrent execution. Don't worry if it feels weird at first -
+//! async takes a bit to click.
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Simulates learning a song (takes 1 second)
+async fn learn_song() -> String {
+    println!("🎵 Learning song...");
+    sleep(Duration::from_millis(1000)).await;
+    println!("📚 Song learned!");
+    "Never Gonna Give You Up".to_string()
+}
+
+/// TODO: Convert this to an async function
+/// 
+/// Make this async so it can:
+/// 1. Print "🎤 Singing: {song}"
+/// 2. Sleep for 500ms using tokio::time::sleep
+/// 3. Print "🎵 Finished singing!"
+fn sing_song(song: String) {
+    todo!("Convert this function to async")
+}
+
+/// TODO: Convert this to an async function
+/// 
+/// Same deal here - make it async and have it:
+/// 1. Print "💃 Dancing!"
+/// 2. Sleep for 1500ms
+/// 3. Print "🕺 Finished dancing!"
+fn dance() {
+    todo!("Convert this function to async")
+}
+
+/// TODO: Implement this async function
+/// 
+/// Chain these together:
+/// 1. Call learn_song() and await its result
+/// 2. Call sing_song() with the learned song and await it
+/// 
+/// This shows how async functions can wait for each other - 
+/// just like synchronous code, but with superpowers!
+async fn learn_and_sing() {
+    todo!("Implement sequential async execution")
+}
+
+/// TODO: Implement this async function
+/// 
+/// Here's where it gets cool - run learn_and_sing() and dance() 
+/// at the same time!
+/// 
+/// Stuck? `cargo run -- hint` reveals a hint at a time.
+async fn async_main() {
+    todo!("Implement concurrent execution")
+}
+
+#[tokio::main]
+async fn main() {
+    println!("🚀 Exercise 1.1: Basic Async/Await\n");
+    
+    // Once you implement the functions above, uncomment this code:
+    /*
+    println!("=== Sequential Execution ===");
+    let start = std::time::Instant::now();
+    
+    let song = learn_song().await;
+    sing_song(song).await;
+    dance().await;
+    
+    println!("⏱️  Sequential took: {:?}\n", start.elapsed());
+    
+    println!("=== Concurrent Execution ===");
+    let start = std::time::Instant::now();
+    
+    async_main().await;
+    
+    println!("⏱️  Concurrent took: {:?}", start.elapsed());
+    
+    println!("\n💡 Notice how concurrent execution is faster!");
+    */
+    
+    println!("⚠️  Complete the TODO sections first!");
+}
+
+// A quick example test to get you going. `check` runs the full test suite
+// on top of this, so no need to add more here (but you're welcome to!).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_functions_are_async() {
+        // This test will only compile if the functions are properly async
+        let _ = sing_song("test".to_string());
+        let _ = dance();
+        let _ = learn_and_sing();
+        let _ = async_main();
+    }
+}
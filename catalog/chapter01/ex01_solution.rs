@@ -0,0 +1,113 @@
+//! Reference solution for Exercise 1.1: Basic Async/Await
+//! 
+//! Time to get our feet wet with async/await syntax! This is where the fun begins.
+//! 
+//! ## Here's what we'll figure out
+//! 
+//! - How to convert regular functions to async ones
+//! - The difference between doing things one-at-a-time vs all-at-once
+//! - Why async code can be so much faster
+//! 
+//! ## Quick context
+//! 
+//! From Chapter 1 of the Rust Async Book:
+//! 
+//! > Async code allows us to write programs that can do multiple things at once.
+//! > Traditional synchronous code executes one operation at a time, blocking until
+//! > each completes. Async code can start multiple operations and switch between
+//! > them as they wait for external resources.
+//! 
+//! The key insight is that many programs spend time waiting - for network requests,
+//! disk I/O, or timers. Async programming lets us use that waiting time productively.
+//! Pretty neat, right?
+//! 
+//! ## Let's dive in
+//! 
+//! Fill in the gaps below to convert synchronous functions to async
+//! and implement concurrent execution. Don't worry if it feels weird at first -
+//! async takes a bit to click.
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Simulates learning a song (takes 1 second)
+async fn learn_song() -> String {
+    println!("🎵 Learning song...");
+    sleep(Duration::from_millis(1000)).await;
+    println!("📚 Song learned!");
+    "Never Gonna Give You Up".to_string()
+}
+
+/// Sings the song (takes 500ms)
+///
+/// `async fn` means calling it only builds a future - nothing is printed
+/// until it is awaited.
+async fn sing_song(song: String) {
+    println!("🎤 Singing: {}", song);
+    sleep(Duration::from_millis(500)).await;
+    println!("🎵 Finished singing!");
+}
+
+/// Dances (takes 1500ms)
+async fn dance() {
+    println!("💃 Dancing!");
+    sleep(Duration::from_millis(1500)).await;
+    println!("🕺 Finished dancing!");
+}
+
+/// Learns the song, then sings it
+///
+/// Each `.await` finishes before the next line runs, so this reads just
+/// like synchronous code - singing needs the song, so it has to wait.
+async fn learn_and_sing() {
+    let song = learn_song().await;
+    sing_song(song).await;
+}
+
+/// Learns and sings while dancing
+///
+/// `join!` polls both futures in turn, so whenever one is waiting on its
+/// sleep the other makes progress. The whole thing takes as long as the
+/// slowest branch (1.5s) instead of the sum (3s).
+async fn async_main() {
+    tokio::join!(learn_and_sing(), dance());
+}
+
+#[tokio::main]
+async fn main() {
+    println!("🚀 Exercise 1.1: Basic Async/Await\n");
+    
+    println!("=== Sequential Execution ===");
+    let start = std::time::Instant::now();
+    
+    let song = learn_song().await;
+    sing_song(song).await;
+    dance().await;
+    
+    println!("⏱️  Sequential took: {:?}\n", start.elapsed());
+    
+    println!("=== Concurrent Execution ===");
+    let start = std::time::Instant::now();
+    
+    async_main().await;
+    
+    println!("⏱️  Concurrent took: {:?}", start.elapsed());
+    
+    println!("\n💡 Notice how concurrent execution is faster!");
+}
+
+// A quick example test to get you going. `check` runs the full test suite
+// on top of this, so no need to add more here (but you're welcome to!).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_functions_are_async() {
+        // This test will only compile if the functions are properly async
+        let _ = sing_song("test".to_string());
+        let _ = dance();
+        let _ = learn_and_sing();
+        let _ = async_main();
+    }
+}
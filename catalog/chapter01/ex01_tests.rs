@@ -0,0 +1,43 @@
+//! Tests for Exercise 1.1: Basic Async/Await
+//!
+//! These are spliced into the exercise by `check` and never copied into
+//! the learner's file. `check` traces the functions listed in the
+//! manifest, so the tests can see which of them overlapped; the timing
+//! tests run on tokio's paused clock.
+
+use super::*;
+use exercise_support::*;
+
+#[tokio::test]
+async fn test_functions_are_async() {
+    // This test will only compile if the functions are properly async
+    let _ = sing_song("test".to_string());
+    let _ = dance();
+    let _ = learn_and_sing();
+    let _ = async_main();
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_learn_and_sing_is_sequential() {
+    // The song has to be learned before it can be sung
+    let ((), trace) = traced(learn_and_sing()).await;
+    trace.assert_sequential(&["learn_song", "sing_song"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dances_while_singing() {
+    let ((), trace) = traced(async_main()).await;
+    trace.assert_concurrent(&["learn_and_sing", "dance"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_concurrent_is_faster() {
+    // Learning and singing (1.5s) should happen while dancing (1.5s)
+    assert_concurrent(async_main(), &[ms(1500), ms(1500)]).await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_no_blocking() {
+    // Waiting should hand the thread back to the runtime, not block it
+    assert_no_blocking(async_main()).await;
+}
@@ -0,0 +1,125 @@
+//! Exercise 1.2: Concurrent Downloads
+//! 
+//! Now we're getting to the good stuff - seeing async really shine
+//! with simulated network downloads.
+//! 
+//! ## What we're exploring
+//! 
+//! - How async makes I/O-bound operations way faster
+//! - Getting comfortable with futures and concurrent execution
+//! - A peek at how async runtimes work their magic
+//! 
+//! ## Here's the deal
+//! 
+//! From Chapter 1.2 of the Rust Async Book:
+//! 
+//! > In a typical threaded application, if you wanted to download two different
+//! > webpages at the same time, you would spread the work across two threads.
+//! > This is resource-intensive - threads have significant memory overhead.
+//! > 
+//! > With async, we can perform concurrent operations on a single thread,
+//! > using far fewer resources while achieving similar performance.
+//! 
+//! ## Your turn
+//! 
+//! Build some download functions and see for yourself how much faster
+//! concurrent downloads are compared to waiting for each one to finish.
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Simulates downloading a file (takes between 500-1500ms)
+async fn download_file(name: &str) -> String {
+    println!("📥 Starting download: {}", name);
+    
+    // Simulate variable download time
+    let duration = match name {
+        "small.txt" => 500,
+        "medium.jpg" => 1000,
+        "large.zip" => 1500,
+        _ => 1000,
+    };
+    
+    sleep(Duration::from_millis(duration)).await;
+    
+    println!("✅ Completed download: {}", name);
+    format!("Contents of {}", name)
+}
+
+/// TODO: Implement sequential downloads
+/// 
+/// Download these files one after another:
+/// - "small.txt"
+/// - "medium.jpg"  
+/// - "large.zip"
+/// 
+/// Return a Vec<String> with all the downloaded contents.
+/// Watch how long this takes!
+async fn download_sequential() -> Vec<String> {
+    todo!("Download files sequentially")
+}
+
+/// TODO: Implement concurrent downloads
+/// 
+/// Now download all three files at the same time.
+/// 
+/// This should be way faster than sequential - that's the power of async!
+async fn download_concurrent() -> Vec<String> {
+    todo!("Download files concurrently")
+}
+
+/// TODO: Implement a download with timeout
+/// 
+/// Try downloading "large.zip" but bail out if it takes more than 1 second.
+/// 
+/// Return Ok(content) if it finishes in time, Err("Download timed out") otherwise.
+/// Sometimes you just can't wait forever, you know?
+/// 
+/// Stuck? `cargo run -- hint` reveals a hint at a time.
+async fn download_with_timeout() -> Result<String, &'static str> {
+    todo!("Implement download with timeout")
+}
+
+#[tokio::main]
+async fn main() {
+    println!("🚀 Exercise 1.2: Concurrent Downloads\n");
+    
+    // Uncomment after implementing the functions:
+    /*
+    println!("=== Sequential Downloads ===");
+    let start = std::time::Instant::now();
+    let files = download_sequential().await;
+    println!("Downloaded {} files", files.len());
+    println!("⏱️  Sequential took: {:?}\n", start.elapsed());
+    
+    println!("=== Concurrent Downloads ===");
+    let start = std::time::Instant::now();
+    let files = download_concurrent().await;
+    println!("Downloaded {} files", files.len());
+    println!("⏱️  Concurrent took: {:?}\n", start.elapsed());
+    
+    println!("=== Download with Timeout ===");
+    match download_with_timeout().await {
+        Ok(content) => println!("✅ Downloaded: {}", content),
+        Err(e) => println!("❌ {}", e),
+    }
+    */
+    
+    println!("⚠️  Complete the TODO sections first!");
+}
+
+// A quick example test to get you going. `check` runs the full test suite
+// on top of this, so no need to add more here (but you're welcome to!).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_sequential_downloads() {
+        let files = download_sequential().await;
+        assert_eq!(files.len(), 3);
+        assert!(files[0].contains("small.txt"));
+        assert!(files[1].contains("medium.jpg"));
+        assert!(files[2].contains("large.zip"));
+    }
+}
@@ -0,0 +1,122 @@
+//! Reference solution for Exercise 1.2: Concurrent Downloads
+//! 
+//! Now we're getting to the good stuff - seeing async really shine
+//! with simulated network downloads.
+//! 
+//! ## What we're exploring
+//! 
+//! - How async makes I/O-bound operations way faster
+//! - Getting comfortable with futures and concurrent execution
+//! - A peek at how async runtimes work their magic
+//! 
+//! ## Here's the deal
+//! 
+//! From Chapter 1.2 of the Rust Async Book:
+//! 
+//! > In a typical threaded application, if you wanted to download two different
+//! > webpages at the same time, you would spread the work across two threads.
+//! > This is resource-intensive - threads have significant memory overhead.
+//! > 
+//! > With async, we can perform concurrent operations on a single thread,
+//! > using far fewer resources while achieving similar performance.
+//! 
+//! ## Your turn
+//! 
+//! Build some download functions and see for yourself how much faster
+//! concurrent downloads are compared to waiting for each one to finish.
+
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+/// Simulates downloading a file (takes between 500-1500ms)
+async fn download_file(name: &str) -> String {
+    println!("📥 Starting download: {}", name);
+    
+    // Simulate variable download time
+    let duration = match name {
+        "small.txt" => 500,
+        "medium.jpg" => 1000,
+        "large.zip" => 1500,
+        _ => 1000,
+    };
+    
+    sleep(Duration::from_millis(duration)).await;
+    
+    println!("✅ Completed download: {}", name);
+    format!("Contents of {}", name)
+}
+
+/// Downloads the files one after another (500ms + 1000ms + 1500ms = 3s)
+///
+/// Awaiting inside the loop means each download only starts once the
+/// previous one is done.
+async fn download_sequential() -> Vec<String> {
+    let mut files = Vec::new();
+    for name in ["small.txt", "medium.jpg", "large.zip"] {
+        files.push(download_file(name).await);
+    }
+    files
+}
+
+/// Downloads all three files at once (1.5s, the slowest download)
+///
+/// All three futures are handed to `join!` before any of them is awaited,
+/// so they all wait on their sleeps at the same time. The results come
+/// back in the order the futures were passed, not the order they finished.
+async fn download_concurrent() -> Vec<String> {
+    let (small, medium, large) = tokio::join!(
+        download_file("small.txt"),
+        download_file("medium.jpg"),
+        download_file("large.zip"),
+    );
+    vec![small, medium, large]
+}
+
+/// Downloads large.zip, giving up after a second
+///
+/// `timeout` races the download against a timer. When the timer wins the
+/// download future is dropped - that cancels it, nothing keeps running.
+async fn download_with_timeout() -> Result<String, &'static str> {
+    timeout(Duration::from_secs(1), download_file("large.zip"))
+        .await
+        .map_err(|_| "Download timed out")
+}
+
+#[tokio::main]
+async fn main() {
+    println!("🚀 Exercise 1.2: Concurrent Downloads\n");
+    
+    println!("=== Sequential Downloads ===");
+    let start = std::time::Instant::now();
+    let files = download_sequential().await;
+    println!("Downloaded {} files", files.len());
+    println!("⏱️  Sequential took: {:?}\n", start.elapsed());
+    
+    println!("=== Concurrent Downloads ===");
+    let start = std::time::Instant::now();
+    let files = download_concurrent().await;
+    println!("Downloaded {} files", files.len());
+    println!("⏱️  Concurrent took: {:?}\n", start.elapsed());
+    
+    println!("=== Download with Timeout ===");
+    match download_with_timeout().await {
+        Ok(content) => println!("✅ Downloaded: {}", content),
+        Err(e) => println!("❌ {}", e),
+    }
+}
+
+// A quick example test to get you going. `check` runs the full test suite
+// on top of this, so no need to add more here (but you're welcome to!).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_sequential_downloads() {
+        let files = download_sequential().await;
+        assert_eq!(files.len(), 3);
+        assert!(files[0].contains("small.txt"));
+        assert!(files[1].contains("medium.jpg"));
+        assert!(files[2].contains("large.zip"));
+    }
+}
@@ -0,0 +1,56 @@
+//! Tests for Exercise 1.2: Concurrent Downloads
+//!
+//! These are spliced into the exercise by `check` and never copied into
+//! the learner's file. `check` traces every `download_file`, so the tests
+//! can see which downloads overlapped; the timing tests run on tokio's
+//! paused clock.
+
+use super::*;
+use exercise_support::*;
+
+#[tokio::test(start_paused = true)]
+async fn test_sequential_downloads() {
+    let files = download_sequential().await;
+    assert_eq!(files.len(), 3);
+    assert!(files[0].contains("small.txt"));
+    assert!(files[1].contains("medium.jpg"));
+    assert!(files[2].contains("large.zip"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_concurrent_downloads() {
+    let files = download_concurrent().await;
+    assert_eq!(files.len(), 3);
+    assert!(files.iter().any(|f| f.contains("small.txt")));
+    assert!(files.iter().any(|f| f.contains("medium.jpg")));
+    assert!(files.iter().any(|f| f.contains("large.zip")));
+}
+
+const DOWNLOADS: [&str; 3] = ["download_file(small.txt)", "download_file(medium.jpg)", "download_file(large.zip)"];
+
+#[tokio::test(start_paused = true)]
+async fn test_sequential_downloads_in_order() {
+    let (_, trace) = traced(download_sequential()).await;
+    trace.assert_sequential(&DOWNLOADS);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_concurrent_downloads_overlap() {
+    let (_, trace) = traced(download_concurrent()).await;
+    trace.assert_concurrent(&DOWNLOADS);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_concurrent_is_faster() {
+    // Concurrent should take about as long as the longest download (1.5s)
+    assert_concurrent(download_concurrent(), &[ms(500), ms(1000), ms(1500)]).await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_timeout() {
+    // Give up after the 1 second limit, don't wait for the 1.5s download
+    match assert_completes_within(ms(1000), download_with_timeout()).await {
+        Ok(_) => panic!("Should have timed out"),
+        Err(msg) => assert_eq!(msg, "Download timed out"),
+    }
+}
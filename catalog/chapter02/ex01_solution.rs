@@ -0,0 +1,132 @@
+//! Reference solution for Exercise 2.1: The Future Trait
+//!
+//! Time to see what makes async tick by building our own futures!
+//!
+//! ## What we're exploring
+//!
+//! - What `poll` returns and when
+//! - Why a future that returns `Poll::Pending` has to hang on to a `Waker`
+//! - How work finishing on another thread gets the task polled again
+//!
+//! ## Quick context
+//!
+//! From Chapter 2.1 of the Rust Async Book:
+//!
+//! > A Future is an asynchronous computation that can produce a value.
+//! > The Future trait is at the core of asynchronous programming in Rust.
+//!
+//! Think of it like a promise that something will happen - eventually.
+//!
+//! And from Chapter 2.2:
+//!
+//! > It's common that futures aren't able to complete the first time they are
+//! > `poll`ed. When this happens, the future needs to ensure that it is polled
+//! > again once it is ready to make more progress. This is done with the `Waker`
+//! > type.
+//!
+//! ## Let's build a timer
+//!
+//! `TimerFuture` starts a thread that sleeps for a while and then flags the
+//! future as done. Your job is the other half: the future has to tell the
+//! executor whether it's done yet, and the thread has to wake the task up
+//! when it is. The checks poll your future by hand and will tell you
+//! exactly what went wrong if it doesn't play by the rules.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+/// A future that completes once its timer thread has slept long enough
+pub struct TimerFuture {
+    shared_state: Arc<Mutex<SharedState>>,
+}
+
+/// State shared between the future and the timer thread
+struct SharedState {
+    /// Whether the sleep time has elapsed
+    completed: bool,
+
+    /// The waker of the task `TimerFuture` is running on. The thread uses
+    /// it to tell the task to poll again once `completed` is set.
+    waker: Option<Waker>,
+}
+
+/// Ready once the thread has marked the timer completed
+///
+/// Until then, `poll` leaves a clone of `cx.waker()` in the shared state
+/// before returning `Poll::Pending` - without it nothing would ever tell the
+/// executor to poll again. The waker is stored on every poll, not just the
+/// first, since the future may have moved to a different task since.
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared_state = self.shared_state.lock().unwrap();
+        if shared_state.completed {
+            Poll::Ready(())
+        } else {
+            shared_state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl TimerFuture {
+    /// Create a new `TimerFuture` which will complete after `duration`
+    pub fn new(duration: Duration) -> Self {
+        let shared_state = Arc::new(Mutex::new(SharedState {
+            completed: false,
+            waker: None,
+        }));
+
+        // Spawn the timer thread
+        let thread_shared_state = shared_state.clone();
+        thread::spawn(move || {
+            thread::sleep(duration);
+
+            // Signal that the timer has completed and wake the task, if it
+            // has been polled yet. `take` leaves `None` behind, so the task
+            // is only woken once.
+            let mut shared_state = thread_shared_state.lock().unwrap();
+            shared_state.completed = true;
+            if let Some(waker) = shared_state.waker.take() {
+                waker.wake()
+            }
+        });
+
+        TimerFuture { shared_state }
+    }
+}
+
+fn main() {
+    println!("🚀 Exercise 2.1: The Future Trait\n");
+
+    println!("⏳ Starting a 1 second timer...");
+    let start = std::time::Instant::now();
+
+    // block_on is a tiny executor: it polls the future, then sleeps until
+    // the waker is called and polls again
+    futures::executor::block_on(TimerFuture::new(Duration::from_secs(1)));
+
+    println!("⏰ Timer fired after {:?}", start.elapsed());
+}
+
+// A quick example test to get you going. `check` runs the full test suite
+// on top of this, so no need to add more here (but you're welcome to!).
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_pending() {
+        // A waker that does nothing - all we want is the first poll
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut timer = TimerFuture::new(Duration::from_secs(1));
+        assert!(Pin::new(&mut timer).poll(&mut cx).is_pending());
+    }
+}
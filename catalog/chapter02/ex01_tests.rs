@@ -0,0 +1,82 @@
+//! Tests for Exercise 2.1: The Future Trait
+//!
+//! These are spliced into the exercise by `check` and never copied into
+//! the learner's file. The future is polled by hand with a counting waker,
+//! so a broken `poll` fails with an explanation instead of hanging.
+
+use super::*;
+use exercise_support::*;
+
+/// How long to wait for a wake before calling it lost
+const WAKE_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[test]
+fn test_starts_pending() {
+    let mut driver = PollDriver::new(TimerFuture::new(ms(200)));
+    assert!(driver.poll().is_pending(), "the timer shouldn't be done before it's had time to sleep");
+}
+
+#[test]
+fn test_pending_registers_waker() {
+    let mut driver = PollDriver::new(TimerFuture::new(ms(200)));
+    assert!(driver.poll().is_pending());
+    if let Some(diagnostic) = driver.diagnose_pending() {
+        panic!("{}", diagnostic);
+    }
+}
+
+#[test]
+fn test_completes_when_woken() {
+    // Polls again only after each wake, like a real executor
+    assert_wakes_correctly(TimerFuture::new(ms(50)), WAKE_TIMEOUT);
+}
+
+#[test]
+fn test_ready_after_completion() {
+    let mut driver = PollDriver::new(TimerFuture::new(ms(20)));
+    assert!(driver.poll().is_pending());
+    driver.wait_for_wake(WAKE_TIMEOUT).unwrap_or_else(|diagnostic| panic!("{}", diagnostic));
+    assert!(driver.poll().is_ready(), "woken, but the timer still says it isn't done");
+}
+
+#[test]
+fn test_wakes_once() {
+    let mut driver = PollDriver::new(TimerFuture::new(ms(20)));
+    assert!(driver.poll().is_pending());
+    driver.wait_for_wake(WAKE_TIMEOUT).unwrap_or_else(|diagnostic| panic!("{}", diagnostic));
+
+    // Give any extra wakes time to show up
+    thread::sleep(ms(100));
+    if let Some(diagnostic) = driver.diagnose_wakes() {
+        panic!("{}", diagnostic);
+    }
+}
+
+#[test]
+fn test_wakes_latest_waker() {
+    // Polled on one task, then moved to another - the second waker is the
+    // one that must be woken
+    let first = CountingWaker::new();
+    let second = CountingWaker::new();
+    let mut timer = TimerFuture::new(ms(50));
+
+    assert!(Pin::new(&mut timer).poll(&mut Context::from_waker(&first.waker())).is_pending());
+    assert!(Pin::new(&mut timer).poll(&mut Context::from_waker(&second.waker())).is_pending());
+
+    assert!(second.wait_for_wakes(1, WAKE_TIMEOUT),
+        "the waker from the latest poll was never woken - store cx.waker().clone() on every poll, not just the first");
+    assert_eq!(first.wakes(), 0, "the waker from an earlier poll was woken instead of the latest one");
+}
+
+#[test]
+fn test_timers_wake_in_order() {
+    let log = WakeLog::new();
+    let mut slow = PollDriver::with_waker(TimerFuture::new(ms(150)), log.waker("slow"));
+    let mut fast = PollDriver::with_waker(TimerFuture::new(ms(30)), log.waker("fast"));
+
+    assert!(slow.poll().is_pending());
+    assert!(fast.poll().is_pending());
+    slow.wait_for_wake(WAKE_TIMEOUT).unwrap_or_else(|diagnostic| panic!("{}", diagnostic));
+
+    assert_eq!(log.order(), ["fast", "slow"], "each timer should wake its task once, when it's done");
+}
@@ -0,0 +1,210 @@
+//! Reference solution for Exercise 2.2: Custom Executor
+//!
+//! Ever wonder how futures actually get run? Let's build a simple
+//! executor and find out!
+//!
+//! ## What we're exploring
+//!
+//! - What an executor does with the futures you hand it
+//! - How waking a task puts it back in the executor's queue
+//! - Why a good executor never polls a future that hasn't been woken
+//!
+//! ## Quick context
+//!
+//! From Chapter 2.3 of the Rust Async Book:
+//!
+//! > Rust's `Future`s are lazy: they won't do anything unless actively driven
+//! > to completion. One way to drive a future to completion is to `.await` it
+//! > inside an `async` function, but that just pushes the problem one level
+//! > up: who will run the futures returned from the top-level `async`
+//! > functions? The answer is that we need a `Future` executor.
+//!
+//! ## The plan
+//!
+//! Our executor keeps a channel of tasks that are ready to make progress.
+//! Spawning sends a new task down the channel, waking a task sends it down
+//! again, and the executor loop pulls tasks off the other end and polls
+//! them. The `TimerFuture` from the last exercise is included so there's
+//! something worth waiting for.
+
+use futures::future::{BoxFuture, FutureExt};
+use futures::task::{waker_ref, ArcWake};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+/// Task executor that receives tasks off of a channel and runs them
+struct Executor {
+    ready_queue: Receiver<Arc<Task>>,
+}
+
+/// `Spawner` spawns new futures onto the task channel
+#[derive(Clone)]
+struct Spawner {
+    task_sender: SyncSender<Arc<Task>>,
+}
+
+/// A future that can reschedule itself to be polled by an `Executor`
+struct Task {
+    /// In-progress future that should be pushed to completion
+    ///
+    /// The `Mutex` is only there so `Task` can be shared between threads -
+    /// only one thread ever polls it at a time.
+    future: Mutex<Option<BoxFuture<'static, ()>>>,
+
+    /// Handle to place the task itself back onto the task queue
+    task_sender: SyncSender<Arc<Task>>,
+}
+
+fn new_executor_and_spawner() -> (Executor, Spawner) {
+    // Maximum number of tasks to allow queueing in the channel at once.
+    // This is just to make `sync_channel` happy, and wouldn't be present in
+    // a real executor.
+    const MAX_QUEUED_TASKS: usize = 10_000;
+    let (task_sender, ready_queue) = sync_channel(MAX_QUEUED_TASKS);
+    (Executor { ready_queue }, Spawner { task_sender })
+}
+
+impl Spawner {
+    /// Spawn a future onto the executor
+    ///
+    /// 1. Box the future up with `future.boxed()`
+    /// 2. Wrap it in a `Task` (in an `Arc`), along with a clone of the sender
+    /// 3. Send the task down the channel, so the executor polls it once
+    fn spawn(&self, future: impl Future<Output = ()> + 'static + Send) {
+        let future = future.boxed();
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(future)),
+            task_sender: self.task_sender.clone(),
+        });
+        self.task_sender.send(task).expect("too many tasks queued");
+    }
+}
+
+impl ArcWake for Task {
+    /// Wake the task
+    ///
+    /// Waking means "poll me again": send a clone of the task back down
+    /// the channel so the executor picks it up.
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        let cloned = arc_self.clone();
+        arc_self.task_sender.send(cloned).expect("too many tasks queued");
+    }
+}
+
+impl Executor {
+    /// Run tasks until every `Spawner` is dropped and the queue is empty
+    ///
+    /// For every task received from `ready_queue`:
+    /// 1. Take the future out of the task's slot. If it's already gone, the
+    ///    task has completed - skip it
+    /// 2. Make a waker from the task with `waker_ref(&task)` and a `Context`
+    ///    from that waker
+    /// 3. Poll the future. If it's still pending, put it back in the slot
+    ///    so it can be polled again once it's woken
+    ///
+    /// `recv` fails once every sender is gone - the spawner and all the tasks
+    /// that finished and were dropped - which ends the loop.
+    fn run(&self) {
+        while let Ok(task) = self.ready_queue.recv() {
+            let mut future_slot = task.future.lock().unwrap();
+            if let Some(mut future) = future_slot.take() {
+                let waker = waker_ref(&task);
+                let context = &mut Context::from_waker(&waker);
+                if future.as_mut().poll(context).is_pending() {
+                    *future_slot = Some(future);
+                }
+            }
+        }
+    }
+}
+
+/// The timer from Exercise 2.1, all done
+pub struct TimerFuture {
+    shared_state: Arc<Mutex<SharedState>>,
+}
+
+struct SharedState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared_state = self.shared_state.lock().unwrap();
+        if shared_state.completed {
+            Poll::Ready(())
+        } else {
+            shared_state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl TimerFuture {
+    pub fn new(duration: Duration) -> Self {
+        let shared_state = Arc::new(Mutex::new(SharedState {
+            completed: false,
+            waker: None,
+        }));
+
+        let thread_shared_state = shared_state.clone();
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut shared_state = thread_shared_state.lock().unwrap();
+            shared_state.completed = true;
+            if let Some(waker) = shared_state.waker.take() {
+                waker.wake()
+            }
+        });
+
+        TimerFuture { shared_state }
+    }
+}
+
+fn main() {
+    println!("🚀 Exercise 2.2: Custom Executor\n");
+
+    let (executor, spawner) = new_executor_and_spawner();
+
+    spawner.spawn(async {
+        println!("👋 Hello from a task!");
+        TimerFuture::new(Duration::from_secs(1)).await;
+        println!("⏰ ...and goodbye, a second later!");
+    });
+
+    // Drop the spawner so the executor knows no more tasks are coming
+    drop(spawner);
+
+    // Runs until the task above is done
+    executor.run();
+}
+
+// A quick example test to get you going. `check` runs the full test suite
+// on top of this, so no need to add more here (but you're welcome to!).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_runs_a_task() {
+        let (executor, spawner) = new_executor_and_spawner();
+        let ran = Arc::new(AtomicBool::new(false));
+
+        let flag = ran.clone();
+        spawner.spawn(async move {
+            flag.store(true, Ordering::SeqCst);
+        });
+        drop(spawner);
+
+        executor.run();
+        assert!(ran.load(Ordering::SeqCst));
+    }
+}
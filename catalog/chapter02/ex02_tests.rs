@@ -0,0 +1,99 @@
+//! Tests for Exercise 2.2: Custom Executor
+//!
+//! These are spliced into the exercise by `check` and never copied into
+//! the learner's file. Futures inside the tasks are wrapped in poll
+//! counters, so an executor that polls without being woken gets caught.
+
+use super::*;
+use exercise_support::*;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Spawn `futures`, drop the spawner and run the executor to the end
+fn run_all(futures: Vec<BoxFuture<'static, ()>>) {
+    let (executor, spawner) = new_executor_and_spawner();
+    for future in futures {
+        spawner.spawn(future);
+    }
+    drop(spawner);
+    executor.run();
+}
+
+#[test]
+fn test_runs_a_task() {
+    let ran = Arc::new(AtomicBool::new(false));
+
+    let flag = ran.clone();
+    run_all(vec![async move { flag.store(true, Ordering::SeqCst) }.boxed()]);
+
+    assert!(ran.load(Ordering::SeqCst), "the spawned task never ran");
+}
+
+#[test]
+fn test_runs_every_task() {
+    let ran = Arc::new(AtomicUsize::new(0));
+
+    let tasks = (0..5)
+        .map(|_| {
+            let ran = ran.clone();
+            async move {
+                ran.fetch_add(1, Ordering::SeqCst);
+            }
+            .boxed()
+        })
+        .collect();
+    run_all(tasks);
+
+    assert_eq!(ran.load(Ordering::SeqCst), 5, "not every spawned task ran");
+}
+
+#[test]
+fn test_resumes_woken_tasks() {
+    let finished = Arc::new(AtomicBool::new(false));
+
+    let flag = finished.clone();
+    run_all(vec![async move {
+        TimerFuture::new(ms(50)).await;
+        flag.store(true, Ordering::SeqCst);
+    }
+    .boxed()]);
+
+    assert!(finished.load(Ordering::SeqCst),
+        "the task was woken but never polled again - wake_by_ref has to send the task back to the executor");
+}
+
+#[test]
+fn test_polls_only_when_woken() {
+    let finished = Arc::new(AtomicBool::new(false));
+
+    // Pending once, woken once, ready - anything more is the executor
+    // polling on its own
+    let flag = finished.clone();
+    run_all(vec![async move {
+        assert_polled_n_times(TimerFuture::new(ms(50)), 2).await;
+        flag.store(true, Ordering::SeqCst);
+    }
+    .boxed()]);
+
+    assert!(finished.load(Ordering::SeqCst), "the task never finished");
+}
+
+#[test]
+fn test_tasks_finish_in_wake_order() {
+    let finished = Arc::new(Mutex::new(vec![]));
+
+    let tasks = [("slow", 150), ("fast", 30), ("medium", 80)]
+        .into_iter()
+        .map(|(name, millis)| {
+            let finished = finished.clone();
+            async move {
+                TimerFuture::new(ms(millis)).await;
+                finished.lock().unwrap().push(name);
+            }
+            .boxed()
+        })
+        .collect();
+    run_all(tasks);
+
+    assert_eq!(*finished.lock().unwrap(), ["fast", "medium", "slow"],
+        "tasks should be polled as they're woken, not in the order they were spawned");
+}
@@ -0,0 +1,17 @@
+//! Exercise 3.1: Async Lifetimes
+//! 
+//! Lifetimes in async can be a real head-scratcher. Let's figure them out together.
+//! 
+//! ## Here's what's up
+//! 
+//! From Chapter 3 of the Rust Async Book:
+//! 
+//! > Lifetimes in async functions follow special rules because the generated
+//! > Future type needs to capture references safely.
+//! 
+//! Don't worry, it clicks once you see the patterns.
+
+// Exercise content will be implemented
+fn main() {
+    println!("Exercise 3.1 - Coming soon!");
+}
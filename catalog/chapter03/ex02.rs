@@ -0,0 +1,18 @@
+//! Exercise 3.2: Async Move
+//! 
+//! Time to tackle move semantics in async - when values need to move into
+//! async blocks and when they don't.
+//! 
+//! ## The lowdown
+//! 
+//! From Chapter 3 of the Rust Async Book:
+//! 
+//! > Understanding when and how to move values into async blocks is crucial
+//! > for avoiding borrow checker fights.
+//! 
+//! We've all been there with the borrow checker, right?
+
+// Exercise content will be implemented
+fn main() {
+    println!("Exercise 3.2 - Coming soon!");
+}
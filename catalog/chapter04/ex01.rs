@@ -0,0 +1,19 @@
+//! Exercise 4.1: Join and Select
+//! 
+//! Working with multiple futures at once - join! waits for all,
+//! select! races to see who finishes first.
+//! 
+//! ## What's happening here
+//! 
+//! From Chapter 4 of the Rust Async Book:
+//! 
+//! > Sometimes you need to run multiple futures concurrently.
+//! > Rust gives you different tools depending on what you need.
+//! 
+//! It's like having multiple pots on the stove - sometimes you need
+//! them all to finish, sometimes just the first one.
+
+// Exercise content will be implemented
+fn main() {
+    println!("Exercise 4.1 - Coming soon!");
+}
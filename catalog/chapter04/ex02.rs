@@ -0,0 +1,19 @@
+//! Exercise 4.2: Spawning Tasks
+//! 
+//! Sometimes you just want to fire off a task and let it do its thing
+//! in the background. That's where spawning comes in.
+//! 
+//! ## Here's the scoop
+//! 
+//! From Chapter 4 of the Rust Async Book:
+//! 
+//! > Spawning lets you run futures in the background without
+//! > having to actively wait for them.
+//! 
+//! Think of it like starting a washing machine - you don't stand
+//! there watching it, you go do other stuff.
+
+// Exercise content will be implemented
+fn main() {
+    println!("Exercise 4.2 - Coming soon!");
+}
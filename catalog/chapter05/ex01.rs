@@ -0,0 +1,19 @@
+//! Exercise 5.1: Basic Streams
+//! 
+//! Time to play with async streams - they're like iterators that can
+//! wait for things.
+//! 
+//! ## What's a stream anyway?
+//! 
+//! From Chapter 5 of the Rust Async Book:
+//! 
+//! > Streams are the async equivalent of iterators. Instead of
+//! > immediately having the next value, they might need to wait for it.
+//! 
+//! Imagine a conveyor belt where items show up when they're ready,
+//! not all at once.
+
+// Exercise content will be implemented
+fn main() {
+    println!("Exercise 5.1 - Coming soon!");
+}
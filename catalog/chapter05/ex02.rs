@@ -0,0 +1,18 @@
+//! Exercise 5.2: Stream Processing
+//! 
+//! Now that you've got the basics, let's do some cool stuff with streams -
+//! filtering, mapping, and all that jazz.
+//! 
+//! ## Let's process some data
+//! 
+//! From Chapter 5 of the Rust Async Book:
+//! 
+//! > Processing streams efficiently is a key async skill. You can
+//! > transform, filter, and combine streams just like iterators.
+//! 
+//! If you're comfortable with iterator adapters, this will feel familiar.
+
+// Exercise content will be implemented
+fn main() {
+    println!("Exercise 5.2 - Coming soon!");
+}
@@ -0,0 +1,136 @@
+//! A clock that only moves when the test says so
+//!
+//! Exercises that build their own timers or executors can't lean on
+//! tokio's clock. They take a [`MockClock`] instead, and the test decides
+//! exactly when time passes - and can check that a sleeping future was
+//! woken when its deadline came, not before and not never.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+#[derive(Default)]
+struct ClockState {
+    /// Time since the clock was created
+    now: Duration,
+    next_id: u64,
+    /// Sleeps waiting for their deadline, with the waker to call then
+    sleepers: Vec<(u64, Duration, Waker)>,
+}
+
+/// A manually advanced clock, cheap to clone and share
+#[derive(Clone, Default)]
+pub struct MockClock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Time passed since the clock was created
+    pub fn now(&self) -> Duration {
+        self.state.lock().unwrap().now
+    }
+
+    /// Move time forward, waking every sleep whose deadline has come
+    pub fn advance(&self, by: Duration) {
+        let due: Vec<Waker> = {
+            let mut state = self.state.lock().unwrap();
+            state.now += by;
+            let now = state.now;
+
+            let (due, waiting) = std::mem::take(&mut state.sleepers)
+                .into_iter()
+                .partition(|(_, deadline, _)| *deadline <= now);
+            state.sleepers = waiting;
+            due.into_iter().map(|(_, _, waker)| waker).collect()
+        };
+
+        // Wake outside the lock, the woken futures may well look at the clock
+        for waker in due {
+            waker.wake();
+        }
+    }
+
+    /// A future that finishes once the clock has advanced by `duration`
+    pub fn sleep(&self, duration: Duration) -> MockSleep {
+        let mut state = self.state.lock().unwrap();
+        state.next_id += 1;
+        MockSleep { clock: self.clone(), id: state.next_id, deadline: state.now + duration }
+    }
+
+    /// How many sleeps are waiting for the clock, i.e. have been polled
+    /// and will be woken by [`advance`](Self::advance)
+    pub fn pending_sleeps(&self) -> usize {
+        self.state.lock().unwrap().sleepers.len()
+    }
+}
+
+/// Future returned by [`MockClock::sleep`]
+pub struct MockSleep {
+    clock: MockClock,
+    id: u64,
+    deadline: Duration,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.clock.state.lock().unwrap();
+        state.sleepers.retain(|(id, _, _)| *id != self.id);
+
+        if state.now >= self.deadline {
+            Poll::Ready(())
+        } else {
+            state.sleepers.push((self.id, self.deadline, cx.waker().clone()));
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for MockSleep {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.clock.state.lock() {
+            state.sleepers.retain(|(id, _, _)| *id != self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wake::PollDriver;
+
+    #[test]
+    fn sleep_is_woken_when_its_deadline_comes() {
+        let clock = MockClock::new();
+        let mut driver = PollDriver::new(clock.sleep(Duration::from_millis(100)));
+
+        assert!(driver.poll().is_pending());
+        assert_eq!(clock.pending_sleeps(), 1);
+
+        clock.advance(Duration::from_millis(99));
+        assert_eq!(driver.wakes(), 0);
+
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(driver.wakes(), 1);
+        assert_eq!(clock.now(), Duration::from_millis(100));
+        assert!(driver.poll().is_ready());
+        assert_eq!(clock.pending_sleeps(), 0);
+    }
+
+    #[test]
+    fn dropped_sleep_stops_waiting() {
+        let clock = MockClock::new();
+        let mut driver = PollDriver::new(clock.sleep(Duration::from_millis(100)));
+        assert!(driver.poll().is_pending());
+
+        drop(driver);
+        assert_eq!(clock.pending_sleeps(), 0);
+    }
+}
@@ -0,0 +1,40 @@
+//! Assertions for exercise test suites
+//!
+//! `check` links this crate into every exercise it builds, so hidden test
+//! suites can say what they expect in a line or two and every exercise
+//! fails with the same kind of message:
+//!
+//! ```no_run
+//! use exercise_support::*;
+//!
+//! # async fn download_concurrent() {}
+//! #[tokio::test(start_paused = true)]
+//! async fn test_downloads_are_concurrent() {
+//!     assert_concurrent(download_concurrent(), &[ms(500), ms(1000), ms(1500)]).await;
+//! }
+//! ```
+//!
+//! Whether operations ran concurrently is best checked by structure, with
+//! [`traced`]: `check` instruments the functions an exercise lists, and
+//! the [`Trace`] says exactly which ones overlapped.
+//!
+//! Timing assertions are meant for tokio's virtual clock
+//! (`start_paused = true`): sleeps take no real time and measure exactly, so
+//! the tests are fast and a busy machine can't make them flaky. Exercises
+//! that build their own timers or executors can use [`MockClock`] instead,
+//! and [`PollDriver`] to check that their futures poll and wake properly.
+
+pub mod clock;
+pub mod poll;
+pub mod timing;
+pub mod trace;
+pub mod wake;
+
+pub use clock::{MockClock, MockSleep};
+pub use poll::{assert_no_blocking, assert_polled_n_times, PollCounter};
+pub use timing::{
+    assert_completes_within, assert_concurrent, assert_ran_concurrently, assert_ran_sequentially,
+    assert_sequential, ms, timed,
+};
+pub use trace::{traced, Span, Trace};
+pub use wake::{assert_wakes_correctly, CountingWaker, Diagnostic, PollDriver, WakeLog};
@@ -0,0 +1,116 @@
+//! Assertions about how a future gets polled
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// A single poll taking longer than this blocked the executor's thread
+const BLOCKING_THRESHOLD: Duration = Duration::from_millis(50);
+
+/// Wraps a future and keeps track of how it gets polled
+pub struct PollCounter<F> {
+    future: Pin<Box<F>>,
+    polls: usize,
+    /// Longest a single call to `poll` took, in real time
+    longest_poll: Duration,
+}
+
+impl<F: Future> PollCounter<F> {
+    pub fn new(future: F) -> Self {
+        PollCounter { future: Box::pin(future), polls: 0, longest_poll: Duration::ZERO }
+    }
+
+    /// How many times the future has been polled so far
+    pub fn polls(&self) -> usize {
+        self.polls
+    }
+
+    /// The longest the future kept the thread busy in one poll
+    pub fn longest_poll(&self) -> Duration {
+        self.longest_poll
+    }
+}
+
+impl<F: Future> Future for PollCounter<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        self.polls += 1;
+
+        // Real time on purpose - blocking the thread doesn't move tokio's clock
+        let start = Instant::now();
+        let result = self.future.as_mut().poll(cx);
+        self.longest_poll = self.longest_poll.max(start.elapsed());
+
+        result
+    }
+}
+
+/// Run `future`, asserting it needed exactly `expected` polls to finish
+///
+/// A future that's polled more often than it should be is usually waking
+/// itself in a loop; one polled less never returned `Pending` where it
+/// was supposed to wait.
+pub async fn assert_polled_n_times<F: Future>(future: F, expected: usize) -> F::Output {
+    let mut counter = PollCounter::new(future);
+    let output = (&mut counter).await;
+    let polls = counter.polls();
+    assert!(
+        polls == expected,
+        "expected the future to be polled {} times, but it was polled {} times - {}",
+        expected, polls,
+        if polls > expected {
+            "it's being polled without having been woken"
+        } else {
+            "it finished without waiting where it should have"
+        }
+    );
+    output
+}
+
+/// Run `future`, asserting it never blocks the executor's thread
+///
+/// Every poll should hand control back quickly. One that takes longer than
+/// 50ms is almost always `std::thread::sleep`, blocking I/O or a busy loop
+/// inside async code.
+pub async fn assert_no_blocking<F: Future>(future: F) -> F::Output {
+    let mut counter = PollCounter::new(future);
+    let output = (&mut counter).await;
+    let longest = counter.longest_poll();
+    assert!(
+        longest < BLOCKING_THRESHOLD,
+        "one poll blocked the thread for {:?} - use tokio::time::sleep and async I/O \
+         instead of std::thread::sleep and std::fs/std::net inside async code",
+        Duration::from_millis(longest.as_millis() as u64)
+    );
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_every_poll() {
+        let output = assert_polled_n_times(async { tokio::task::yield_now().await; 3 }, 2).await;
+        assert_eq!(output, 3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "finished without waiting where it should have")]
+    async fn too_few_polls_fail() {
+        assert_polled_n_times(async {}, 2).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tokio_sleep_does_not_block() {
+        assert_no_blocking(tokio::time::sleep(Duration::from_secs(5))).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "blocked the thread")]
+    async fn thread_sleep_blocks() {
+        assert_no_blocking(async { std::thread::sleep(Duration::from_millis(100)) }).await;
+    }
+}
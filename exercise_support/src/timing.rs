@@ -0,0 +1,148 @@
+//! Timing assertions, on tokio's clock
+//!
+//! Only tokio's timers move the virtual clock. Code that blocks with
+//! `std::thread::sleep` measures as taking no time, which the assertions
+//! below report as such.
+
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Slack allowed on top of the expected time
+///
+/// Virtual time is exact, this only covers rounding to the timer's
+/// millisecond resolution.
+const TOLERANCE: Duration = Duration::from_millis(5);
+
+/// Shorthand for `Duration::from_millis`
+pub fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+/// Run a future to completion, along with the time it took
+pub async fn timed<F: Future>(future: F) -> (F::Output, Duration) {
+    let start = Instant::now();
+    let output = future.await;
+    (output, start.elapsed())
+}
+
+fn describe(parts: &[Duration]) -> String {
+    parts.iter().map(|part| format!("{:?}", part)).collect::<Vec<_>>().join(" + ")
+}
+
+fn assert_advanced(elapsed: Duration, parts: &[Duration]) {
+    let longest = parts.iter().max().copied().unwrap_or_default();
+    assert!(
+        elapsed + TOLERANCE >= longest,
+        "took {:?}, less than its slowest part ({:?}) - is something sleeping with \
+         std::thread::sleep instead of tokio::time::sleep?",
+        elapsed, longest
+    );
+}
+
+/// Assert that operations taking `parts` each ran at the same time: the
+/// whole thing took as long as the slowest of them, not their sum
+pub fn assert_ran_concurrently(elapsed: Duration, parts: &[Duration]) {
+    assert_advanced(elapsed, parts);
+
+    let longest = parts.iter().max().copied().unwrap_or_default();
+    let total: Duration = parts.iter().sum();
+    assert!(
+        elapsed <= longest + TOLERANCE,
+        "expected {} to run concurrently in {:?}, but it took {:?}{}",
+        describe(parts), longest, elapsed,
+        if elapsed + TOLERANCE >= total { " - they ran one after another" } else { " - only some of them overlapped" }
+    );
+}
+
+/// Assert that operations taking `parts` ran one after another: the whole
+/// thing took at least their sum
+pub fn assert_ran_sequentially(elapsed: Duration, parts: &[Duration]) {
+    assert_advanced(elapsed, parts);
+
+    let total: Duration = parts.iter().sum();
+    assert!(
+        elapsed + TOLERANCE >= total,
+        "expected {} to run one after another in {:?}, but it took only {:?} - either \
+         some of them ran at the same time, or something isn't sleeping with tokio::time::sleep",
+        describe(parts), total, elapsed
+    );
+}
+
+/// Run `future`, asserting that the operations inside it taking `parts`
+/// ran concurrently
+pub async fn assert_concurrent<F: Future>(future: F, parts: &[Duration]) -> F::Output {
+    let (output, elapsed) = timed(future).await;
+    assert_ran_concurrently(elapsed, parts);
+    output
+}
+
+/// Run `future`, asserting that the operations inside it taking `parts`
+/// ran one after another
+pub async fn assert_sequential<F: Future>(future: F, parts: &[Duration]) -> F::Output {
+    let (output, elapsed) = timed(future).await;
+    assert_ran_sequentially(elapsed, parts);
+    output
+}
+
+/// Run `future`, failing the test if it hasn't finished after `limit`
+///
+/// On a paused clock a future that's never woken gets here instantly,
+/// instead of hanging the test.
+pub async fn assert_completes_within<F: Future>(limit: Duration, future: F) -> F::Output {
+    match tokio::time::timeout(limit, future).await {
+        Ok(output) => output,
+        Err(_) => panic!(
+            "didn't complete within {:?} - is a future returning Poll::Pending without \
+             arranging for its Waker to be called?",
+            limit
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::sleep;
+
+    #[tokio::test(start_paused = true)]
+    async fn joined_sleeps_are_concurrent() {
+        let output = assert_concurrent(async { tokio::join!(sleep(ms(500)), sleep(ms(1000))); 7 }, &[ms(500), ms(1000)]).await;
+        assert_eq!(output, 7);
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(expected = "they ran one after another")]
+    async fn awaited_sleeps_are_not_concurrent() {
+        assert_concurrent(async { sleep(ms(500)).await; sleep(ms(1000)).await; }, &[ms(500), ms(1000)]).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn awaited_sleeps_are_sequential() {
+        assert_sequential(async { sleep(ms(500)).await; sleep(ms(1000)).await; }, &[ms(500), ms(1000)]).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(expected = "either some of them ran at the same time")]
+    async fn joined_sleeps_are_not_sequential() {
+        assert_sequential(async { tokio::join!(sleep(ms(500)), sleep(ms(1000))); }, &[ms(500), ms(1000)]).await;
+    }
+
+    #[test]
+    #[should_panic(expected = "less than its slowest part")]
+    fn no_time_at_all_means_blocking_sleeps() {
+        assert_ran_concurrently(Duration::ZERO, &[ms(500), ms(1000)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn completes_within_its_limit() {
+        let output = assert_completes_within(ms(1000), async { sleep(ms(999)).await; "done" }).await;
+        assert_eq!(output, "done");
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(expected = "didn't complete within 1s")]
+    async fn never_woken_future_fails_instead_of_hanging() {
+        assert_completes_within(ms(1000), std::future::pending::<()>()).await;
+    }
+}
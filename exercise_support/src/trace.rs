@@ -0,0 +1,232 @@
+//! Operation tracing, for checking concurrency by structure
+//!
+//! Elapsed time says *that* something ran sequentially, not *what*. Here
+//! every traced operation records when it started and finished instead,
+//! so a test can say "dance started only after learn_and_sing finished".
+//!
+//! `check` does the instrumenting: the functions an exercise lists under
+//! `trace = [...]` get their bodies wrapped in [`span`] before the build,
+//! so the learner's file stays untouched. A test then runs the code under
+//! [`traced`] and asserts on the [`Trace`] it gets back.
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Events recorded since the last [`traced`] started
+static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Start,
+    End,
+}
+
+/// An operation starting or finishing
+#[derive(Debug, Clone)]
+pub struct Event {
+    /// Tells apart operations of the same name
+    pub id: usize,
+    pub name: String,
+    pub kind: EventKind,
+    pub at: Instant,
+}
+
+fn push(id: usize, name: &str, kind: EventKind) {
+    EVENTS.lock().unwrap().push(Event { id, name: name.to_string(), kind, at: Instant::now() });
+}
+
+/// Run `future` as a traced operation called `name`
+///
+/// It starts when it's first polled and ends when it completes.
+pub async fn span<F: Future>(name: impl Into<String>, future: F) -> F::Output {
+    static NEXT_ID: Mutex<usize> = Mutex::new(0);
+
+    let name = name.into();
+    let id = {
+        let mut next = NEXT_ID.lock().unwrap();
+        *next += 1;
+        *next
+    };
+
+    push(id, &name, EventKind::Start);
+    let output = future.await;
+    push(id, &name, EventKind::End);
+    output
+}
+
+/// Run `future`, collecting every traced operation it performs
+pub async fn traced<F: Future>(future: F) -> (F::Output, Trace) {
+    EVENTS.lock().unwrap().clear();
+    let start = Instant::now();
+
+    let output = future.await;
+
+    let events = std::mem::take(&mut *EVENTS.lock().unwrap());
+    (output, Trace { start, events })
+}
+
+/// One traced operation, from start to finish
+#[derive(Debug, Clone)]
+pub struct Span {
+    pub name: String,
+    /// When it started, relative to the start of the trace
+    pub start: Duration,
+    /// When it finished, `None` if it never did
+    pub end: Option<Duration>,
+    /// Positions of its events in the trace, for ordering events that
+    /// happened at the same instant
+    start_seq: usize,
+    end_seq: Option<usize>,
+}
+
+impl Span {
+    /// Whether the two were in progress at the same time
+    pub fn overlaps(&self, other: &Span) -> bool {
+        let ends_after = |span: &Span, seq: usize| match span.end_seq {
+            Some(end) => end > seq,
+            None => true,
+        };
+        ends_after(self, other.start_seq) && ends_after(other, self.start_seq)
+    }
+
+    /// Whether this one finished before `other` started
+    pub fn finished_before(&self, other: &Span) -> bool {
+        self.end_seq.is_some_and(|end| end < other.start_seq)
+    }
+}
+
+/// Everything traced during a run
+#[derive(Debug, Clone)]
+pub struct Trace {
+    start: Instant,
+    events: Vec<Event>,
+}
+
+impl Trace {
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Every operation, in the order they started
+    pub fn spans(&self) -> Vec<Span> {
+        let mut spans: Vec<(usize, Span)> = vec![];
+
+        for (seq, event) in self.events.iter().enumerate() {
+            let at = event.at.duration_since(self.start);
+            match event.kind {
+                EventKind::Start => spans.push((event.id, Span {
+                    name: event.name.clone(),
+                    start: at,
+                    end: None,
+                    start_seq: seq,
+                    end_seq: None,
+                })),
+                EventKind::End => {
+                    if let Some((_, span)) = spans.iter_mut().find(|(id, _)| *id == event.id) {
+                        span.end = Some(at);
+                        span.end_seq = Some(seq);
+                    }
+                }
+            }
+        }
+
+        spans.into_iter().map(|(_, span)| span).collect()
+    }
+
+    /// The first operation called `name`, failing the test if it never ran
+    pub fn span(&self, name: &str) -> Span {
+        self.spans().into_iter().find(|span| span.name == name).unwrap_or_else(|| {
+            panic!("{} never ran - is it still async, still called that, and actually awaited?", name)
+        })
+    }
+
+    /// Print the spans for the `timeline` command to chart
+    ///
+    /// One tab-separated line says the variant finished, then one line per
+    /// span: the variant, the name, and the start and end in microseconds
+    /// (`-` if it never ended).
+    pub fn report(&self, variant: &str) {
+        println!("__timeline\t{}", variant);
+        for span in self.spans() {
+            let end = span.end.map_or_else(|| "-".to_string(), |end| end.as_micros().to_string());
+            println!("__timeline\t{}\t{}\t{}\t{}", variant, span.name, span.start.as_micros(), end);
+        }
+    }
+
+    /// Assert that all of `names` were in progress at the same time,
+    /// naming every pair that ran one after the other instead
+    pub fn assert_concurrent(&self, names: &[&str]) {
+        let spans: Vec<Span> = names.iter().map(|name| self.span(name)).collect();
+
+        let mut problems = vec![];
+        for (i, a) in spans.iter().enumerate() {
+            for b in &spans[i + 1..] {
+                if a.finished_before(b) {
+                    problems.push(format!("{} started only after {} finished", b.name, a.name));
+                } else if b.finished_before(a) {
+                    problems.push(format!("{} started only after {} finished", a.name, b.name));
+                }
+            }
+        }
+
+        assert!(
+            problems.is_empty(),
+            "expected {} to run at the same time, but they ran one after another:\n  - {}\n\
+             Start them together (with tokio::join!, for example) instead of awaiting one \
+             before starting the next.",
+            names.join(", "), problems.join("\n  - ")
+        );
+    }
+
+    /// Assert that `names` ran strictly one after another, in that order
+    pub fn assert_sequential(&self, names: &[&str]) {
+        let spans: Vec<Span> = names.iter().map(|name| self.span(name)).collect();
+
+        let problems: Vec<String> = spans
+            .windows(2)
+            .filter(|pair| !pair[0].finished_before(&pair[1]))
+            .map(|pair| {
+                if pair[1].finished_before(&pair[0]) {
+                    format!("{} ran before {}", pair[1].name, pair[0].name)
+                } else {
+                    format!("{} started before {} finished", pair[1].name, pair[0].name)
+                }
+            })
+            .collect();
+
+        assert!(
+            problems.is_empty(),
+            "expected {} to run one after another, in that order, but:\n  - {}\n\
+             Await each one before starting the next.",
+            names.join(" → "), problems.join("\n  - ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::sleep;
+
+    async fn op(name: &str, millis: u64) {
+        span(name, sleep(Duration::from_millis(millis))).await
+    }
+
+    // The recorded events are shared by the whole process, so every trace
+    // is taken in this one test
+    #[tokio::test(start_paused = true)]
+    async fn traces_show_what_overlapped() {
+        let (_, joined) = traced(async { tokio::join!(op("sing", 200), op("dance", 100)) }).await;
+        joined.assert_concurrent(&["sing", "dance"]);
+        let dance = joined.span("dance");
+        assert_eq!((dance.start, dance.end), (Duration::ZERO, Some(Duration::from_millis(100))));
+
+        let (_, awaited) = traced(async { op("learn", 100).await; op("sing", 100).await; }).await;
+        awaited.assert_sequential(&["learn", "sing"]);
+        assert!(!awaited.span("learn").overlaps(&awaited.span("sing")));
+        assert!(std::panic::catch_unwind(|| awaited.assert_concurrent(&["learn", "sing"])).is_err());
+        assert!(std::panic::catch_unwind(|| joined.assert_sequential(&["sing", "dance"])).is_err());
+    }
+}
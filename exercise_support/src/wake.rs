@@ -0,0 +1,372 @@
+//! Poll and wake instrumentation for hand-written futures
+//!
+//! Timing can't tell whether a future built on `Poll` and `Waker` plays by
+//! the rules. These tools poll it by hand with a waker that counts, so a
+//! test can see exactly what happened between polls:
+//!
+//! - [`CountingWaker`] counts wakes and notices whether the future kept a
+//!   clone of it around
+//! - [`PollDriver`] polls a future step by step, like a one-task executor
+//! - [`WakeLog`] records the order several wakers were woken in
+//!
+//! When something's off it comes back as a [`Diagnostic`], worded for the
+//! learner rather than as a raw assertion.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
+
+struct WakeCounter {
+    wakes: Mutex<usize>,
+    woken: Condvar,
+    /// Where to record wakes, for wakers handed out by a `WakeLog`
+    log: Option<(String, WakeLog)>,
+}
+
+impl Wake for WakeCounter {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if let Some((label, log)) = &self.log {
+            log.order.lock().unwrap().push(label.clone());
+        }
+        *self.wakes.lock().unwrap() += 1;
+        self.woken.notify_all();
+    }
+}
+
+/// A waker that counts how often it's woken
+#[derive(Clone)]
+pub struct CountingWaker {
+    counter: Arc<WakeCounter>,
+    /// One reference per `CountingWaker` clone, to tell them apart from
+    /// the `Waker`s sharing `counter`
+    handles: Arc<()>,
+}
+
+impl Default for CountingWaker {
+    fn default() -> Self {
+        Self::with_log(None)
+    }
+}
+
+impl CountingWaker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_log(log: Option<(String, WakeLog)>) -> Self {
+        CountingWaker {
+            counter: Arc::new(WakeCounter { wakes: Mutex::new(0), woken: Condvar::new(), log }),
+            handles: Arc::new(()),
+        }
+    }
+
+    /// A `Waker` to poll with - every clone of it reports back here
+    pub fn waker(&self) -> Waker {
+        Waker::from(self.counter.clone())
+    }
+
+    /// How many times any of its wakers has been woken
+    pub fn wakes(&self) -> usize {
+        *self.counter.wakes.lock().unwrap()
+    }
+
+    /// Number of live `Waker`s created by [`waker`](Self::waker)
+    pub fn wakers_alive(&self) -> usize {
+        Arc::strong_count(&self.counter) - Arc::strong_count(&self.handles)
+    }
+
+    /// Block until the wake count reaches `wakes`, giving up after `timeout`
+    ///
+    /// Returns whether it got there.
+    pub fn wait_for_wakes(&self, wakes: usize, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let mut count = self.counter.wakes.lock().unwrap();
+        while *count < wakes {
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            count = self.counter.woken.wait_timeout(count, deadline - now).unwrap().0;
+        }
+        true
+    }
+}
+
+/// The order a set of labelled wakers were woken in
+#[derive(Clone, Default)]
+pub struct WakeLog {
+    order: Arc<Mutex<Vec<String>>>,
+}
+
+impl WakeLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A counting waker that records `label` here every time it's woken
+    pub fn waker(&self, label: &str) -> CountingWaker {
+        CountingWaker::with_log(Some((label.to_string(), self.clone())))
+    }
+
+    /// Labels of every wake so far, oldest first
+    pub fn order(&self) -> Vec<String> {
+        self.order.lock().unwrap().clone()
+    }
+}
+
+/// Something a hand-written future did wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// Returned `Pending` without keeping a clone of the waker or waking
+    /// it, so nothing can ever wake it again
+    PendingWithoutWaker { poll: usize },
+    /// Returned `Pending` and nothing woke it in time
+    NeverWoken { poll: usize, waited: Duration },
+    /// Woken more times than it returned `Pending`
+    WokenTooOften { pending: usize, wakes: usize },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::PendingWithoutWaker { poll } => write!(
+                f,
+                "poll #{} returned Poll::Pending without registering the waker - nothing \
+                 can wake the task again. Store cx.waker().clone() somewhere the code \
+                 finishing the work can reach it, before returning Pending.",
+                poll
+            ),
+            Diagnostic::NeverWoken { poll, waited } => write!(
+                f,
+                "poll #{} returned Poll::Pending and the waker still hadn't been called \
+                 after {:?}. Whatever completes the work has to call wake() on the \
+                 stored waker.",
+                poll, waited
+            ),
+            Diagnostic::WokenTooOften { pending, wakes } => write!(
+                f,
+                "the task was woken {} times but only returned Poll::Pending {} times. \
+                 Each wake makes the executor poll again, so wake once per Pending.",
+                wakes, pending
+            ),
+        }
+    }
+}
+
+/// Polls a future by hand, one step at a time
+pub struct PollDriver<F> {
+    future: Pin<Box<F>>,
+    waker: CountingWaker,
+    polls: usize,
+    pendings: usize,
+    /// Whether the last `Pending` left a way to wake the task
+    registered: bool,
+}
+
+impl<F: Future> PollDriver<F> {
+    pub fn new(future: F) -> Self {
+        Self::with_waker(future, CountingWaker::new())
+    }
+
+    /// Poll with a particular waker, e.g. one from a [`WakeLog`]
+    pub fn with_waker(future: F, waker: CountingWaker) -> Self {
+        PollDriver { future: Box::pin(future), waker, polls: 0, pendings: 0, registered: true }
+    }
+
+    /// Poll the future once
+    pub fn poll(&mut self) -> Poll<F::Output> {
+        self.polls += 1;
+        let wakes_before = self.waker.wakes();
+
+        let waker = self.waker.waker();
+        let result = self.future.as_mut().poll(&mut Context::from_waker(&waker));
+        drop(waker);
+
+        if result.is_pending() {
+            self.pendings += 1;
+            // A clone still alive will be woken later; a wake during the
+            // poll already asked for the next one
+            self.registered = self.waker.wakers_alive() > 0 || self.waker.wakes() > wakes_before;
+        }
+
+        result
+    }
+
+    pub fn polls(&self) -> usize {
+        self.polls
+    }
+
+    pub fn wakes(&self) -> usize {
+        self.waker.wakes()
+    }
+
+    /// What's wrong with the last `Pending`, if anything can be told
+    /// right away
+    pub fn diagnose_pending(&self) -> Option<Diagnostic> {
+        (!self.registered).then_some(Diagnostic::PendingWithoutWaker { poll: self.polls })
+    }
+
+    /// Whether the task has been woken more often than it asked to be
+    pub fn diagnose_wakes(&self) -> Option<Diagnostic> {
+        let wakes = self.wakes();
+        (wakes > self.pendings).then_some(Diagnostic::WokenTooOften { pending: self.pendings, wakes })
+    }
+
+    /// Wait until the task is woken after its last `Pending`
+    pub fn wait_for_wake(&self, timeout: Duration) -> Result<(), Diagnostic> {
+        if let Some(diagnostic) = self.diagnose_pending() {
+            return Err(diagnostic);
+        }
+
+        if self.waker.wait_for_wakes(self.pendings, timeout) {
+            Ok(())
+        } else {
+            Err(Diagnostic::NeverWoken { poll: self.polls, waited: timeout })
+        }
+    }
+
+    /// Drive the future to completion like an executor that only polls
+    /// when woken, checking every step
+    pub fn run(mut self, timeout: Duration) -> Result<F::Output, Diagnostic> {
+        loop {
+            if let Poll::Ready(output) = self.poll() {
+                return match self.diagnose_wakes() {
+                    Some(diagnostic) => Err(diagnostic),
+                    None => Ok(output),
+                };
+            }
+            self.wait_for_wake(timeout)?;
+        }
+    }
+}
+
+/// Drive `future` to completion by hand, failing the test with a
+/// [`Diagnostic`] if it breaks the rules of `Poll` and `Waker`
+///
+/// `timeout` is how long to wait for each wake.
+pub fn assert_wakes_correctly<F: Future>(future: F, timeout: Duration) -> F::Output {
+    PollDriver::new(future).run(timeout).unwrap_or_else(|diagnostic| panic!("{}", diagnostic))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::poll_fn;
+    use std::thread;
+
+    /// Returns `Pending` once, keeping the waker in `slot`, then `Ready`
+    fn stores_waker(slot: Arc<Mutex<Option<Waker>>>) -> impl Future<Output = u32> {
+        let mut polled = false;
+        poll_fn(move |cx| {
+            if polled {
+                return Poll::Ready(1);
+            }
+            polled = true;
+            *slot.lock().unwrap() = Some(cx.waker().clone());
+            Poll::Pending
+        })
+    }
+
+    #[test]
+    fn pending_without_keeping_the_waker_is_reported() {
+        let mut driver = PollDriver::new(poll_fn(|_| Poll::<()>::Pending));
+        assert!(driver.poll().is_pending());
+        assert_eq!(driver.diagnose_pending(), Some(Diagnostic::PendingWithoutWaker { poll: 1 }));
+
+        let diagnostic = PollDriver::new(poll_fn(|_| Poll::<()>::Pending)).run(Duration::from_secs(1));
+        assert_eq!(diagnostic.err(), Some(Diagnostic::PendingWithoutWaker { poll: 1 }));
+    }
+
+    #[test]
+    fn stored_waker_woken_later_is_fine() {
+        let slot = Arc::new(Mutex::new(None));
+        let mut driver = PollDriver::new(stores_waker(slot.clone()));
+        assert!(driver.poll().is_pending());
+        assert_eq!(driver.diagnose_pending(), None);
+
+        let waker: Waker = slot.lock().unwrap().take().unwrap();
+        thread::spawn(move || waker.wake()).join().unwrap();
+
+        assert_eq!(driver.wait_for_wake(Duration::from_secs(1)), Ok(()));
+        assert_eq!(driver.poll(), Poll::Ready(1));
+        assert_eq!((driver.polls(), driver.wakes()), (2, 1));
+        assert_eq!(driver.diagnose_wakes(), None);
+    }
+
+    #[test]
+    fn waking_during_the_poll_counts_as_registered() {
+        let mut polled = false;
+        let future = poll_fn(move |cx| {
+            if polled {
+                return Poll::Ready("done");
+            }
+            polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        });
+        assert_eq!(assert_wakes_correctly(future, Duration::from_secs(1)), "done");
+    }
+
+    #[test]
+    fn stored_waker_never_woken_is_reported() {
+        let slot = Arc::new(Mutex::new(None));
+        let result = PollDriver::new(stores_waker(slot)).run(Duration::from_millis(10));
+        assert_eq!(result.err(), Some(Diagnostic::NeverWoken { poll: 1, waited: Duration::from_millis(10) }));
+    }
+
+    #[test]
+    fn waking_twice_per_pending_is_reported() {
+        let mut polled = false;
+        let future = poll_fn(move |cx| {
+            if polled {
+                return Poll::Ready(());
+            }
+            polled = true;
+            cx.waker().wake_by_ref();
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        });
+        let result = PollDriver::new(future).run(Duration::from_secs(1));
+        assert_eq!(result.err(), Some(Diagnostic::WokenTooOften { pending: 1, wakes: 2 }));
+    }
+
+    #[test]
+    fn wake_log_keeps_the_order() {
+        let log = WakeLog::new();
+        let (first, second) = (log.waker("first"), log.waker("second"));
+
+        second.waker().wake();
+        first.waker().wake_by_ref();
+        second.waker().wake_by_ref();
+
+        assert_eq!(log.order(), vec!["second", "first", "second"]);
+        assert_eq!((first.wakes(), second.wakes()), (1, 2));
+    }
+
+    #[test]
+    fn wakers_alive_counts_wakers_not_handles() {
+        let counting = CountingWaker::new();
+        assert_eq!(counting.wakers_alive(), 0);
+
+        let waker = counting.waker();
+        let clone = waker.clone();
+        assert_eq!(counting.wakers_alive(), 2);
+
+        // Cloning the handle itself doesn't make a waker
+        let handle = counting.clone();
+        assert_eq!(handle.wakers_alive(), 2);
+
+        drop(waker);
+        assert_eq!(counting.wakers_alive(), 1);
+        clone.wake();
+        assert_eq!(counting.wakers_alive(), 0);
+        assert_eq!(handle.wakes(), 1);
+    }
+}
@@ -0,0 +1,84 @@
+//! Tutorial configuration
+//!
+//! Settings are read from `.async-book.toml` in the working directory.
+//! Everything is optional - a missing file just means the defaults.
+//!
+//! ```toml
+//! # Extra directories to search for exercise packs
+//! pack_dirs = ["../team-exercises"]
+//!
+//! # Never let `check` touch the network
+//! offline = true
+//!
+//! # Build checks against a `cargo vendor` directory instead of crates.io
+//! vendor_dir = "vendor"
+//!
+//! # Give up on a build after 10 minutes, and on a test after 20 seconds
+//! build_timeout_secs = 600
+//! test_timeout_secs = 20
+//!
+//! # How screens look: "colorful", "plain" or "compact"
+//! theme = "plain"
+//! ```
+
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use colored::*;
+use crate::render::Theme;
+
+const CONFIG_FILE: &str = ".async-book.toml";
+
+/// Extra pack directories, separated like `PATH`
+const PACKS_ENV: &str = "ASYNC_BOOK_PACKS";
+
+/// A cold build of the check workspace compiles tokio and futures
+const DEFAULT_BUILD_TIMEOUT_SECS: u64 = 300;
+
+/// The exercise tests take a few seconds at most
+const DEFAULT_TEST_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Directories containing exercise packs (or a single pack)
+    pub pack_dirs: Vec<PathBuf>,
+    /// Pass `--offline` to every cargo invocation made by `check`
+    pub offline: bool,
+    /// Vendored crates (from `cargo vendor`) to check exercises against
+    pub vendor_dir: Option<PathBuf>,
+    /// How long compiling an exercise may take before `check` stops it
+    pub build_timeout_secs: Option<u64>,
+    /// How long a single test may run before it counts as hung
+    pub test_timeout_secs: Option<u64>,
+    /// How screens are drawn, unless `--theme` says otherwise
+    pub theme: Theme,
+}
+
+impl Config {
+    pub fn build_timeout(&self) -> Duration {
+        Duration::from_secs(self.build_timeout_secs.unwrap_or(DEFAULT_BUILD_TIMEOUT_SECS))
+    }
+
+    pub fn test_timeout(&self) -> Duration {
+        Duration::from_secs(self.test_timeout_secs.unwrap_or(DEFAULT_TEST_TIMEOUT_SECS))
+    }
+}
+
+pub fn load_config() -> Config {
+    let mut config: Config = match fs::read_to_string(CONFIG_FILE) {
+        Ok(content) => toml::from_str(&content).unwrap_or_else(|e| {
+            eprintln!("{} {}: {}", "⚠️  Ignoring".bright_yellow(), CONFIG_FILE, e);
+            Config::default()
+        }),
+        Err(_) => Config::default(),
+    };
+
+    if let Some(paths) = env::var_os(PACKS_ENV) {
+        config.pack_dirs.extend(env::split_paths(&paths).filter(|p| !p.as_os_str().is_empty()));
+    }
+
+    config
+}
@@ -0,0 +1,74 @@
+//! Backups of learner exercise files
+//!
+//! Before an exercise file gets overwritten - by a forced regeneration or a
+//! restore - the current contents are copied to
+//! `.async-book-backups/<exercise>/<timestamp>.rs`. Backups are named by
+//! their timestamp, which is also what `restore <id> <backup>` takes.
+//! They live outside `exercises/` so that `reset` leaves them alone.
+
+use chrono::Local;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::Exercise;
+
+pub const BACKUP_DIR: &str = ".async-book-backups";
+
+/// A saved copy of an exercise file
+#[derive(Debug, Clone)]
+pub struct Backup {
+    /// Timestamp name, e.g. `2024-05-01_14-03-22`
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Directory holding the backups of one exercise
+fn backup_dir(exercise: &Exercise) -> PathBuf {
+    Path::new(BACKUP_DIR).join(exercise.relative_stem())
+}
+
+/// Copy the exercise file into a fresh timestamped backup
+pub fn create_backup(exercise: &Exercise) -> io::Result<Backup> {
+    let dir = backup_dir(exercise);
+    fs::create_dir_all(&dir)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+    let mut name = timestamp.clone();
+    let mut suffix = 2;
+    while dir.join(format!("{}.rs", name)).exists() {
+        name = format!("{}-{}", timestamp, suffix);
+        suffix += 1;
+    }
+
+    let path = dir.join(format!("{}.rs", name));
+    fs::copy(exercise.filename(), &path)?;
+
+    Ok(Backup { name, path })
+}
+
+/// All backups of an exercise, oldest first
+pub fn list_backups(exercise: &Exercise) -> Vec<Backup> {
+    let Ok(entries) = fs::read_dir(backup_dir(exercise)) else {
+        return vec![];
+    };
+
+    let mut backups: Vec<Backup> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            Some(Backup { name, path })
+        })
+        .collect();
+
+    backups.sort_by(|a, b| a.name.cmp(&b.name));
+    backups
+}
+
+/// Find a backup by name
+pub fn find_backup(exercise: &Exercise, name: &str) -> Option<Backup> {
+    let name = name.trim_end_matches(".rs");
+    list_backups(exercise).into_iter().find(|backup| backup.name == name)
+}
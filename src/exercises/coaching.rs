@@ -0,0 +1,297 @@
+//! Coaching for compiler errors
+//!
+//! The build runs with `--message-format=json`, so every diagnostic comes
+//! back with its error code and location. The ones async code is famous
+//! for get an explanation in plain words after the compiler's own output,
+//! plus where in the async book (`async-book-source/src`) to read up on it.
+
+use colored::*;
+use serde::Deserialize;
+use std::path::Path;
+
+/// The async book's chapters
+const BOOK_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/async-book-source/src");
+
+/// One line of cargo's JSON output - only compiler messages are of interest
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<Diagnostic>,
+}
+
+/// A rustc diagnostic, as much of it as coaching needs
+#[derive(Debug, Clone, Deserialize)]
+pub struct Diagnostic {
+    pub message: String,
+    /// `error`, `warning`, `note`...
+    pub level: String,
+    pub code: Option<DiagnosticCode>,
+    #[serde(default)]
+    pub spans: Vec<DiagnosticSpan>,
+    /// The diagnostic as rustc would have printed it
+    pub rendered: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiagnosticCode {
+    /// `E0277`, or a lint name like `unused_must_use`
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub line_start: usize,
+    pub is_primary: bool,
+}
+
+impl Diagnostic {
+    fn code(&self) -> &str {
+        self.code.as_ref().map_or("", |code| code.code.as_str())
+    }
+
+    /// Line of the primary span, if it's in `file`
+    pub fn line_in(&self, file: &str) -> Option<usize> {
+        self.spans
+            .iter()
+            .find(|span| span.is_primary && span.file_name == file)
+            .map(|span| span.line_start)
+    }
+}
+
+/// An explanation for one kind of diagnostic
+struct Coaching {
+    title: &'static str,
+    applies: fn(&Diagnostic) -> bool,
+    explanation: &'static [&'static str],
+    /// Chapter file under `async-book-source/src`, and the section to read
+    chapter: &'static str,
+    section: &'static str,
+}
+
+const COACHING: &[Coaching] = &[
+    Coaching {
+        title: "This future isn't Send",
+        applies: |d| d.message.contains("cannot be sent between threads safely"),
+        explanation: &[
+            "Spawned tasks can move between threads, so everything a future holds",
+            "across an .await has to be Send. An Rc, a RefCell borrow or a",
+            "std::sync::MutexGuard still alive at an .await makes the whole future",
+            "non-Send. Drop it (or end its scope) before the .await, or switch to",
+            "Arc / tokio::sync::Mutex.",
+        ],
+        chapter: "07_workarounds/03_send_approximation.md",
+        section: "`Send` Approximation",
+    },
+    Coaching {
+        title: "Something borrowed may not live long enough - missing `move`?",
+        applies: |d| d.code() == "E0373",
+        explanation: &[
+            "An async block or closure borrows a local variable, but it may still be",
+            "running after the function returns - a spawned task or thread can",
+            "outlive whoever started it. Write `async move { ... }` (or `move || ...`)",
+            "so it owns what it uses, and clone first if you still need the value.",
+        ],
+        chapter: "03_async_await/01_chapter.md",
+        section: "`async move`",
+    },
+    Coaching {
+        title: "A recursive async fn needs boxing",
+        applies: |d| d.code() == "E0733",
+        explanation: &[
+            "An async fn's future contains the futures of everything it awaits, so",
+            "one that awaits itself would be infinitely big. Put the recursive call",
+            "behind a pointer: `Box::pin(my_fn(n - 1)).await`.",
+        ],
+        chapter: "07_workarounds/04_recursion.md",
+        section: "Recursion",
+    },
+    Coaching {
+        title: ".await outside of async code",
+        applies: |d| d.code() == "E0728",
+        explanation: &[
+            ".await can only pause code that's async itself. Make the function an",
+            "`async fn` (and .await it where it's called) or move the code into an",
+            "`async { ... }` block. For main, #[tokio::main] allows `async fn main`.",
+        ],
+        chapter: "01_getting_started/04_async_await_primer.md",
+        section: "`async`/`.await` Primer",
+    },
+    Coaching {
+        title: "Awaiting something that isn't a future",
+        applies: |d| d.code() == "E0277" && d.message.ends_with("is not a future"),
+        explanation: &[
+            ".await only works on futures, and this is a plain value - usually",
+            "because the function being called is still a regular `fn`. Make it",
+            "an `async fn`, or drop the .await.",
+        ],
+        chapter: "01_getting_started/04_async_await_primer.md",
+        section: "`async`/`.await` Primer",
+    },
+    Coaching {
+        title: "A future that's never awaited",
+        applies: |d| d.code() == "unused_must_use" && d.message.contains("Future"),
+        explanation: &[
+            "Futures are lazy: calling an async fn only creates the future, and",
+            "none of its code runs until it's .awaited (or handed to join!, select!",
+            "or spawn). Without the .await the call does nothing at all.",
+        ],
+        chapter: "part-guide/async-await.md",
+        section: "Async functions",
+    },
+];
+
+/// Pick the compiler's diagnostics out of `cargo --message-format=json` output
+pub fn parse(stdout: &[u8]) -> Vec<Diagnostic> {
+    String::from_utf8_lossy(stdout).lines().filter_map(parse_line).collect()
+}
+
+/// The diagnostic on one line of cargo's JSON output, if there is one
+pub fn parse_line(line: &str) -> Option<Diagnostic> {
+    serde_json::from_str::<CargoMessage>(line)
+        .ok()
+        .filter(|message| message.reason == "compiler-message")
+        .and_then(|message| message.message)
+}
+
+/// Print the diagnostics the way rustc would have, pointing at the
+/// learner's file rather than its copy in the check workspace
+pub fn print_diagnostics(diagnostics: &[Diagnostic], source: &str, filename: &str) {
+    for rendered in diagnostics.iter().filter_map(|d| d.rendered.as_deref()) {
+        print!("{}", rendered.replace(source, filename));
+    }
+}
+
+/// Explain the async-specific diagnostics, each kind once
+pub fn print_coaching(diagnostics: &[Diagnostic], source: &str, filename: &str) {
+    let found: Vec<(&Coaching, Vec<usize>)> = COACHING
+        .iter()
+        .filter_map(|coaching| {
+            let matching: Vec<&Diagnostic> = diagnostics.iter().filter(|d| (coaching.applies)(d)).collect();
+            if matching.is_empty() {
+                return None;
+            }
+            let mut lines: Vec<usize> = matching.iter().filter_map(|d| d.line_in(source)).collect();
+            lines.dedup();
+            Some((coaching, lines))
+        })
+        .collect();
+
+    if found.is_empty() {
+        return;
+    }
+
+    // Relative to where the tutorial runs from, when it runs from the crate
+    let book_dir = Path::new(BOOK_DIR);
+    let book_dir = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| book_dir.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| book_dir.to_path_buf());
+
+    println!();
+    println!("{}", "🧑‍🏫 What the compiler is telling you".bright_yellow().bold());
+    for (coaching, lines) in found {
+        println!();
+        let location = lines.iter().map(|line| format!("{}:{}", filename, line)).collect::<Vec<_>>().join(", ");
+        println!("   {} {}", coaching.title.bright_white().bold(), location.bright_black());
+        for line in coaching.explanation {
+            println!("   {}", line);
+        }
+        println!("   📖 {} ({})", book_dir.join(coaching.chapter).display().to_string().bright_cyan(), coaching.section);
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercises::workspace;
+
+    /// `cargo build --message-format=json` output from the check workspace
+    /// for a handful of broken exercises: the main diagnostic of each build,
+    /// between a dependency's artifact and the end of the build
+    const CAPTURED: &str = include_str!("testdata/compiler-messages.jsonl");
+
+    fn source() -> String {
+        workspace::source_file("ch01_ex01")
+    }
+
+    fn diagnostic(code: &str, message: &str) -> Diagnostic {
+        parse(CAPTURED.as_bytes())
+            .into_iter()
+            .find(|d| d.code() == code && d.message.contains(message))
+            .unwrap_or_else(|| panic!("no captured {} diagnostic about {:?}", code, message))
+    }
+
+    fn coaching(diagnostic: &Diagnostic) -> Option<&'static Coaching> {
+        COACHING.iter().find(|coaching| (coaching.applies)(diagnostic))
+    }
+
+    /// Title, chapter and section of the coaching a diagnostic gets
+    fn explained(diagnostic: &Diagnostic) -> (&'static str, &'static str, &'static str) {
+        let coaching = coaching(diagnostic).expect("the diagnostic should get an explanation");
+        (coaching.title, coaching.chapter, coaching.section)
+    }
+
+    #[test]
+    fn only_compiler_messages_are_diagnostics() {
+        let diagnostics = parse(CAPTURED.as_bytes());
+        assert_eq!(diagnostics.len(), CAPTURED.lines().count() - 2);
+        assert_eq!(diagnostics.iter().filter(|d| d.level == "error").count(), diagnostics.len() - 1);
+        assert!(parse_line(r#"{"reason":"build-finished","success":false}"#).is_none());
+        assert!(parse_line("   Compiling tokio v1.53.3").is_none());
+    }
+
+    #[test]
+    fn send_errors_point_at_send_approximation() {
+        let expected = ("This future isn't Send", "07_workarounds/03_send_approximation.md", "`Send` Approximation");
+        assert_eq!(explained(&diagnostic("E0277", "cannot be sent between threads safely")), expected);
+        // Across an .await rustc reports it without a code
+        assert_eq!(explained(&diagnostic("", "future cannot be sent between threads safely")), expected);
+    }
+
+    #[test]
+    fn borrowed_locals_suggest_async_move() {
+        let error = diagnostic("E0373", "may outlive the current function");
+        assert_eq!(explained(&error), ("Something borrowed may not live long enough - missing `move`?",
+            "03_async_await/01_chapter.md", "`async move`"));
+        assert!(coaching(&error).unwrap().explanation.join(" ").contains("async move { ... }"));
+        assert_eq!(error.line_in(&source()), Some(3));
+    }
+
+    #[test]
+    fn recursion_suggests_boxing() {
+        let error = diagnostic("E0733", "recursion in an async fn");
+        assert_eq!(explained(&error), ("A recursive async fn needs boxing", "07_workarounds/04_recursion.md", "Recursion"));
+        assert!(coaching(&error).unwrap().explanation.join(" ").contains("Box::pin"));
+    }
+
+    #[test]
+    fn await_outside_async_points_at_the_primer() {
+        let error = diagnostic("E0728", "only allowed inside `async`");
+        assert_eq!(explained(&error), (".await outside of async code",
+            "01_getting_started/04_async_await_primer.md", "`async`/`.await` Primer"));
+        assert_eq!(error.line_in(&source()), Some(2));
+        assert_eq!(error.line_in("src/main.rs"), None);
+    }
+
+    #[test]
+    fn awaiting_a_plain_value_and_unawaited_futures_are_explained() {
+        assert_eq!(explained(&diagnostic("E0277", "is not a future")).0, "Awaiting something that isn't a future");
+        assert_eq!(explained(&diagnostic("unused_must_use", "Future")).0, "A future that's never awaited");
+    }
+
+    #[test]
+    fn other_errors_get_no_explanation() {
+        let mismatched = parse_line(r#"{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[],"rendered":null}}"#)
+            .expect("a compiler message");
+        assert!(coaching(&mismatched).is_none());
+    }
+
+    #[test]
+    fn every_chapter_is_in_the_book() {
+        for coaching in COACHING {
+            assert!(Path::new(BOOK_DIR).join(coaching.chapter).exists(), "{} is missing", coaching.chapter);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Colored diffs between versions of an exercise file, unified or side by side
+
+use colored::*;
+use similar::{ChangeTag, DiffTag, TextDiff};
+
+/// Print a unified diff from `old` to `new`, three lines of context per hunk
+pub fn print_unified(old: &str, new: &str, old_label: &str, new_label: &str) {
+    let diff = TextDiff::from_lines(old, new);
+
+    if diff.ratio() == 1.0 {
+        println!("   {}", "No differences.".bright_black());
+        return;
+    }
+
+    println!("   {} {}", "---".bright_red(), old_label);
+    println!("   {} {}", "+++".bright_green(), new_label);
+
+    for group in diff.grouped_ops(3) {
+        let (first, last) = (&group[0], &group[group.len() - 1]);
+        println!("   {}", format!("@@ -{},{} +{},{} @@",
+            first.old_range().start + 1, last.old_range().end - first.old_range().start,
+            first.new_range().start + 1, last.new_range().end - first.new_range().start).bright_cyan());
+
+        for op in group {
+            for change in diff.iter_changes(&op) {
+                let line = change.value().trim_end_matches('\n');
+                match change.tag() {
+                    ChangeTag::Delete => println!("   {}", format!("-{}", line).bright_red()),
+                    ChangeTag::Insert => println!("   {}", format!("+{}", line).bright_green()),
+                    ChangeTag::Equal => println!("   {}", format!(" {}", line).bright_black()),
+                }
+            }
+        }
+    }
+}
+
+/// Width to lay a side-by-side diff out in - `COLUMNS` when the shell
+/// exports it, otherwise something most terminals fit
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS").ok().and_then(|columns| columns.parse().ok()).unwrap_or(120)
+}
+
+/// Cut or pad a line to exactly `width` characters
+fn fit(line: &str, width: usize) -> String {
+    let line = line.trim_end_matches('\n').replace('\t', "    ");
+    if line.chars().count() > width {
+        let cut: String = line.chars().take(width.saturating_sub(1)).collect();
+        format!("{}…", cut)
+    } else {
+        format!("{:<width$}", line, width = width)
+    }
+}
+
+/// Print `old` and `new` next to each other, changed lines highlighted and
+/// three lines of context around each change
+pub fn print_side_by_side(old: &str, new: &str, old_label: &str, new_label: &str) {
+    let diff = TextDiff::from_lines(old, new);
+
+    if diff.ratio() == 1.0 {
+        println!("   {}", "No differences.".bright_black());
+        return;
+    }
+
+    // Line numbers, a marker and the gutter take 8 columns per side
+    let width = (terminal_width().saturating_sub(3 + 2 * 8) / 2).max(20);
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+
+    println!("   {}   {}", fit(old_label, width + 7).bright_red().bold(), new_label.bright_green().bold());
+
+    for (index, group) in diff.grouped_ops(3).iter().enumerate() {
+        if index > 0 {
+            println!("   {}", "⋯".bright_cyan());
+        }
+
+        for op in group {
+            let (tag, old_range, new_range) = op.as_tag_tuple();
+            let rows = old_range.len().max(new_range.len());
+
+            for row in 0..rows {
+                let old_line = (row < old_range.len()).then(|| old_range.start + row);
+                let new_line = (row < new_range.len()).then(|| new_range.start + row);
+
+                let side = |line: Option<usize>, lines: &[&str], marker: &str| match line {
+                    Some(line) => format!("{:>4} {} {}", line + 1, marker, fit(lines[line], width)),
+                    None => " ".repeat(width + 7),
+                };
+
+                // Nothing comes after the right side, so it isn't padded
+                let (left, right) = match tag {
+                    DiffTag::Equal => (
+                        side(old_line, &old_lines, " ").bright_black(),
+                        side(new_line, &new_lines, " ").trim_end().bright_black(),
+                    ),
+                    _ => (
+                        side(old_line, &old_lines, "-").bright_red(),
+                        side(new_line, &new_lines, "+").trim_end().bright_green(),
+                    ),
+                };
+                println!("   {} {} {}", left, "│".bright_black(), right);
+            }
+        }
+    }
+}
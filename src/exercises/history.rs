@@ -0,0 +1,71 @@
+//! Attempt history
+//!
+//! Every `check` snapshots the learner's exercise file along with how the
+//! check went, so the way a solution evolved can be reviewed later. Each
+//! exercise gets a directory under `.async-book-history/` holding numbered
+//! snapshots (`0001.rs`, `0002.rs`, ...) and an `attempts.json` index.
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{CheckOutcome, Exercise};
+
+pub const HISTORY_DIR: &str = ".async-book-history";
+
+const INDEX_FILE: &str = "attempts.json";
+
+/// One recorded `check`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub number: u32,
+    /// Local time of the check, e.g. `2024-05-01 14:03:22`
+    pub timestamp: String,
+    pub outcome: CheckOutcome,
+}
+
+/// Directory holding the attempts of one exercise
+fn history_dir(exercise: &Exercise) -> PathBuf {
+    Path::new(HISTORY_DIR).join(exercise.relative_stem())
+}
+
+/// Where the snapshot of an attempt is stored
+pub fn snapshot_path(exercise: &Exercise, number: u32) -> PathBuf {
+    history_dir(exercise).join(format!("{:04}.rs", number))
+}
+
+/// All recorded attempts of an exercise, oldest first
+pub fn load_attempts(exercise: &Exercise) -> Vec<Attempt> {
+    fs::read_to_string(history_dir(exercise).join(INDEX_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Snapshot `code`, the exercise file as it was checked
+pub fn record_attempt(exercise: &Exercise, outcome: CheckOutcome, code: &str) -> io::Result<Attempt> {
+    let dir = history_dir(exercise);
+    fs::create_dir_all(&dir)?;
+
+    let mut attempts = load_attempts(exercise);
+    let attempt = Attempt {
+        number: attempts.last().map_or(1, |last| last.number + 1),
+        timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        outcome,
+    };
+
+    fs::write(snapshot_path(exercise, attempt.number), code)?;
+    attempts.push(attempt.clone());
+
+    let json = serde_json::to_string_pretty(&attempts)?;
+    fs::write(dir.join(INDEX_FILE), json)?;
+
+    Ok(attempt)
+}
+
+/// Contents of an attempt's snapshot
+pub fn read_snapshot(exercise: &Exercise, number: u32) -> Option<String> {
+    fs::read_to_string(snapshot_path(exercise, number)).ok()
+}
@@ -0,0 +1,96 @@
+//! Tracing instrumentation for exercise sources
+//!
+//! Exercises can ask for functions to be traced (`trace = [...]` in the
+//! manifest), so their tests can check what ran alongside what - see
+//! `exercise_support::trace`. Before the build, the body of each traced
+//! `async fn` in the learner's code is wrapped in a span:
+//!
+//! ```text
+//! async fn dance() { BODY }
+//! async fn dance() { let __trace_label = ...; ::exercise_support::trace::span(__trace_label, async move { BODY }).await }
+//! ```
+//!
+//! A name can carry one of the function's arguments into the label, e.g.
+//! `download_file(name)` traces `download_file(small.txt)`. Everything is
+//! inserted on the lines the braces are already on, so compiler errors
+//! still point at the right line of the learner's file.
+
+use proc_macro2::LineColumn;
+
+/// Where a traced function's body braces are, as byte offsets
+struct Body {
+    open: usize,
+    close: usize,
+    label: String,
+}
+
+/// Byte offset of a line/column position (1-based lines, columns in chars)
+fn offset(source: &str, line_starts: &[usize], position: LineColumn) -> usize {
+    let start = line_starts[position.line - 1];
+    source[start..]
+        .char_indices()
+        .nth(position.column)
+        .map_or(source.len(), |(index, _)| start + index)
+}
+
+/// Rust expression producing the span label for a `trace` entry
+fn label_expr(entry: &str) -> Option<(String, String)> {
+    match entry.split_once('(') {
+        Some((name, argument)) => {
+            let argument = argument.strip_suffix(')')?.trim();
+            Some((name.trim().to_string(), format!("format!(\"{}({{}})\", {})", name.trim(), argument)))
+        }
+        None => Some((entry.to_string(), format!("{:?}", entry))),
+    }
+}
+
+/// Wrap the bodies of the traced functions in spans
+///
+/// Functions that aren't found or aren't async (yet) are left alone, and
+/// so is a file that doesn't parse - the build will say what's wrong.
+pub fn instrument(source: &str, traced: &[String]) -> String {
+    if traced.is_empty() {
+        return source.to_string();
+    }
+
+    let Ok(file) = syn::parse_file(source) else {
+        return source.to_string();
+    };
+
+    let labels: Vec<(String, String)> = traced.iter().filter_map(|entry| label_expr(entry)).collect();
+
+    let mut line_starts = vec![0];
+    line_starts.extend(source.match_indices('\n').map(|(index, _)| index + 1));
+
+    let mut bodies: Vec<Body> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(function) if function.sig.asyncness.is_some() => Some(function),
+            _ => None,
+        })
+        .filter_map(|function| {
+            let name = function.sig.ident.to_string();
+            let (_, label) = labels.iter().find(|(traced, _)| *traced == name)?;
+            let braces = function.block.brace_token.span;
+            Some(Body {
+                open: offset(source, &line_starts, braces.open().start()),
+                close: offset(source, &line_starts, braces.close().start()),
+                label: label.clone(),
+            })
+        })
+        .collect();
+
+    // Splice from the back so earlier offsets stay valid
+    bodies.sort_by_key(|body| std::cmp::Reverse(body.open));
+
+    let mut instrumented = source.to_string();
+    for body in bodies {
+        instrumented.insert_str(body.close, "}).await ");
+        instrumented.insert_str(body.open + 1, &format!(
+            " let __trace_label = {}; ::exercise_support::trace::span(__trace_label, async move {{",
+            body.label));
+    }
+
+    instrumented
+}
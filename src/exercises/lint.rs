@@ -0,0 +1,409 @@
+//! Static checks for blocking code in async contexts
+//!
+//! `std::thread::sleep` inside an `async fn` still makes a lax timing test
+//! pass - it just blocks every other task on the thread while it does. So
+//! before building, `check` reads the learner's file with syn and looks for:
+//!
+//! - blocking calls (`std::thread::sleep`, `std::fs`, `std::net`) in async code
+//! - `block_on` in async code, which blocks the runtime it's running on
+//! - calls to the file's own async functions whose future is dropped
+//!   without ever being awaited, as a statement or with `let _ =`
+//!
+//! Code inside closures counts as sync (it's what `spawn_blocking` is for),
+//! and `#[cfg(test)]` modules are left alone. Each exercise decides whether
+//! findings are just warnings or fail the check (`lint = "deny"`).
+
+use colored::*;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+
+/// What `check` does about findings, set per exercise in the manifest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// Don't look
+    Off,
+    /// Report them, but let the check pass
+    #[default]
+    Warn,
+    /// Report them and fail the check
+    Deny,
+}
+
+/// Blocking APIs, as full paths (a trailing `::` covers a whole module),
+/// and what to use in async code instead
+const BLOCKING: &[(&str, &str)] = &[
+    ("std::thread::sleep", "tokio::time::sleep(...).await"),
+    ("std::fs::", "tokio::fs, or tokio::task::spawn_blocking"),
+    ("std::net::", "tokio::net"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FindingKind {
+    /// A blocking call in async code, with the async alternative
+    Blocking { call: String, instead: &'static str },
+    /// `block_on` in async code
+    BlockOn,
+    /// An async function called without awaiting its future
+    NotAwaited { function: String },
+}
+
+/// Something the lint found, and the line it's on
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub line: usize,
+    pub kind: FindingKind,
+}
+
+impl Finding {
+    /// What's wrong, and what to do instead
+    pub fn describe(&self) -> (String, String) {
+        match &self.kind {
+            FindingKind::Blocking { call, instead } => (
+                format!("{} blocks the thread, so no other task on it can run meanwhile", call),
+                format!("use {} instead", instead),
+            ),
+            FindingKind::BlockOn => (
+                "block_on blocks the runtime while it waits, and can deadlock it".to_string(),
+                ".await the future instead".to_string(),
+            ),
+            FindingKind::NotAwaited { function } => (
+                format!("{}() creates a future that's never awaited, so none of it runs", function),
+                "add .await".to_string(),
+            ),
+        }
+    }
+}
+
+/// Find blocking calls and lost futures in `source`
+///
+/// A file that doesn't parse has no findings - the build will say what's wrong.
+pub fn lint(source: &str) -> Vec<Finding> {
+    let Ok(file) = syn::parse_file(source) else {
+        return vec![];
+    };
+
+    let mut collector = Collector::default();
+    collector.visit_file(&file);
+
+    let mut linter = Linter {
+        imports: collector.imports,
+        async_fns: collector.async_fns,
+        in_async: false,
+        findings: vec![],
+    };
+    linter.visit_file(&file);
+
+    linter.findings.sort_by_key(|finding| finding.line);
+    linter.findings
+}
+
+/// Print what the lint found, if anything
+pub fn print_findings(findings: &[Finding], filename: &str, level: LintLevel) {
+    if findings.is_empty() {
+        return;
+    }
+
+    let heading = match level {
+        LintLevel::Deny => "🚫 Async code that won't behave".bright_red().bold(),
+        _ => "⚠️  Async code that won't behave".bright_yellow().bold(),
+    };
+    println!("{}", heading);
+    for finding in findings {
+        let (problem, fix) = finding.describe();
+        println!("   {} {}", format!("{}:{}", filename, finding.line).bright_white(), problem);
+        println!("      {} {}", "→".bright_cyan(), fix);
+    }
+    if level == LintLevel::Deny {
+        println!("   {}", "This exercise won't pass until these are fixed.".bright_red());
+    }
+    println!();
+}
+
+/// Whether an item is compiled for tests only
+fn is_test_only(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg") && attr.parse_args::<syn::Path>().is_ok_and(|path| path.is_ident("test"))
+    })
+}
+
+/// First pass: what's imported under which name, and which functions are async
+#[derive(Default)]
+struct Collector {
+    imports: HashMap<String, String>,
+    async_fns: HashSet<String>,
+}
+
+impl Collector {
+    fn add_use(&mut self, prefix: &str, tree: &syn::UseTree) {
+        let join = |name: &syn::Ident| match prefix {
+            "" => name.to_string(),
+            _ => format!("{}::{}", prefix, name),
+        };
+
+        match tree {
+            syn::UseTree::Path(path) => self.add_use(&join(&path.ident), &path.tree),
+            syn::UseTree::Name(name) if name.ident == "self" => {
+                if let Some(last) = prefix.rsplit("::").next() {
+                    self.imports.insert(last.to_string(), prefix.to_string());
+                }
+            }
+            syn::UseTree::Name(name) => {
+                self.imports.insert(name.ident.to_string(), join(&name.ident));
+            }
+            syn::UseTree::Rename(rename) => {
+                self.imports.insert(rename.rename.to_string(), join(&rename.ident));
+            }
+            syn::UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.add_use(prefix, tree);
+                }
+            }
+            syn::UseTree::Glob(_) => {}
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Collector {
+    fn visit_item_use(&mut self, item: &'ast syn::ItemUse) {
+        self.add_use("", &item.tree);
+    }
+
+    fn visit_signature(&mut self, sig: &'ast syn::Signature) {
+        if sig.asyncness.is_some() {
+            self.async_fns.insert(sig.ident.to_string());
+        }
+    }
+}
+
+/// Second pass: walk the code, keeping track of whether it's async
+struct Linter {
+    imports: HashMap<String, String>,
+    async_fns: HashSet<String>,
+    in_async: bool,
+    findings: Vec<Finding>,
+}
+
+impl Linter {
+    fn push(&mut self, spanned: &impl Spanned, kind: FindingKind) {
+        self.findings.push(Finding { line: spanned.span().start().line, kind });
+    }
+
+    /// Full path of a called function, with imports resolved
+    fn resolve(&self, path: &syn::Path) -> String {
+        let segments: Vec<String> = path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+        let first = &segments[0];
+        match self.imports.get(first) {
+            Some(full) => std::iter::once(full.clone()).chain(segments[1..].iter().cloned()).collect::<Vec<_>>().join("::"),
+            None => segments.join("::"),
+        }
+    }
+
+    /// Run `walk` with `in_async` set to `async_context`
+    fn scoped(&mut self, async_context: bool, walk: impl FnOnce(&mut Self)) {
+        let outer = std::mem::replace(&mut self.in_async, async_context);
+        walk(self);
+        self.in_async = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for Linter {
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        if !is_test_only(&item.attrs) {
+            visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        if !is_test_only(&item.attrs) {
+            self.scoped(item.sig.asyncness.is_some(), |linter| visit::visit_item_fn(linter, item));
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        self.scoped(item.sig.asyncness.is_some(), |linter| visit::visit_impl_item_fn(linter, item));
+    }
+
+    fn visit_expr_async(&mut self, expr: &'ast syn::ExprAsync) {
+        self.scoped(true, |linter| visit::visit_expr_async(linter, expr));
+    }
+
+    fn visit_expr_closure(&mut self, expr: &'ast syn::ExprClosure) {
+        self.scoped(expr.asyncness.is_some(), |linter| visit::visit_expr_closure(linter, expr));
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let (true, syn::Expr::Path(path)) = (self.in_async, &*call.func) {
+            let resolved = self.resolve(&path.path);
+            let blocking = BLOCKING.iter().find(|(api, _)| match api.strip_suffix("::") {
+                Some(module) => resolved.starts_with(api) || resolved == module,
+                None => resolved == *api,
+            });
+
+            if let Some((_, instead)) = blocking {
+                self.push(&call.func, FindingKind::Blocking { call: resolved, instead });
+            } else if resolved.ends_with("block_on") {
+                self.push(&call.func, FindingKind::BlockOn);
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if self.in_async && call.method == "block_on" {
+            self.push(&call.method, FindingKind::BlockOn);
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast syn::Stmt) {
+        // `my_async_fn();` or `let _ = my_async_fn();` - the future is
+        // created and dropped on the spot
+        let dropped = match stmt {
+            syn::Stmt::Expr(expr, Some(_)) => Some(expr),
+            syn::Stmt::Local(local) if matches!(local.pat, syn::Pat::Wild(_)) => {
+                local.init.as_ref().map(|init| &*init.expr)
+            }
+            _ => None,
+        };
+
+        if let Some(expr) = dropped {
+            let function = match expr {
+                syn::Expr::Call(call) => match &*call.func {
+                    syn::Expr::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+                    _ => None,
+                },
+                syn::Expr::MethodCall(call) => Some(call.method.to_string()),
+                _ => None,
+            };
+
+            if let Some(function) = function.filter(|function| self.async_fns.contains(function)) {
+                self.push(expr, FindingKind::NotAwaited { function });
+            }
+        }
+        visit::visit_stmt(self, stmt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(source: &str) -> Vec<(usize, FindingKind)> {
+        lint(source).into_iter().map(|finding| (finding.line, finding.kind)).collect()
+    }
+
+    fn thread_sleep() -> FindingKind {
+        FindingKind::Blocking { call: "std::thread::sleep".to_string(), instead: "tokio::time::sleep(...).await" }
+    }
+
+    #[test]
+    fn thread_sleep_in_an_async_fn_is_blocking() {
+        assert_eq!(kinds("\
+use std::time::Duration;
+
+async fn dance() {
+    std::thread::sleep(Duration::from_millis(500));
+}
+"), vec![(4, thread_sleep())]);
+    }
+
+    #[test]
+    fn imported_thread_sleep_is_resolved() {
+        assert_eq!(kinds("\
+use std::thread;
+use std::thread::sleep as nap;
+
+async fn dance() {
+    thread::sleep(std::time::Duration::from_millis(500));
+    nap(std::time::Duration::from_millis(500));
+}
+"), vec![(5, thread_sleep()), (6, thread_sleep())]);
+    }
+
+    #[test]
+    fn thread_sleep_in_sync_code_is_fine() {
+        assert!(lint("\
+fn pause() {
+    std::thread::sleep(std::time::Duration::from_millis(500));
+}
+
+async fn dance() {
+    tokio::task::spawn_blocking(|| std::thread::sleep(std::time::Duration::from_millis(500))).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+}
+").is_empty());
+    }
+
+    #[test]
+    fn blocking_modules_are_covered() {
+        let found = kinds("\
+async fn load() -> String {
+    let _ = std::net::TcpStream::connect(\"example.com:80\");
+    std::fs::read_to_string(\"songs.txt\").unwrap()
+}
+");
+        assert_eq!(found.len(), 2);
+        assert!(matches!(&found[0].1, FindingKind::Blocking { call, .. } if call == "std::net::TcpStream::connect"));
+        assert!(matches!(&found[1].1, FindingKind::Blocking { call, .. } if call == "std::fs::read_to_string"));
+    }
+
+    #[test]
+    fn block_on_inside_a_runtime_is_flagged() {
+        assert_eq!(kinds("\
+async fn learn() -> String { String::new() }
+
+async fn sing() {
+    let song = futures::executor::block_on(learn());
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(async { futures::executor::block_on(learn()) });
+}
+
+fn main() {
+    futures::executor::block_on(sing());
+}
+"), vec![(4, FindingKind::BlockOn), (6, FindingKind::BlockOn), (6, FindingKind::BlockOn)]);
+    }
+
+    #[test]
+    fn unawaited_calls_are_flagged() {
+        let not_awaited = |function: &str| FindingKind::NotAwaited { function: function.to_string() };
+        assert_eq!(kinds("\
+async fn dance() {}
+async fn sing() {}
+
+async fn perform() {
+    dance();
+    let _ = sing();
+    let _dancing = dance();
+    sing().await;
+    let () = dance().await;
+}
+"), vec![(5, not_awaited("dance")), (6, not_awaited("sing"))]);
+    }
+
+    #[test]
+    fn test_modules_are_left_alone() {
+        assert!(lint("\
+async fn dance() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dance() {
+        let _ = dance();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+}
+").is_empty());
+    }
+
+    #[test]
+    fn files_that_do_not_parse_have_no_findings() {
+        assert!(lint("async fn dance( {").is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+//! Exercise manifests
+//!
+//! Exercises live on disk in packs. A pack is a directory with a `pack.toml`
+//! and one sub-directory per chapter. Each chapter directory has a
+//! `chapter.toml` describing its exercises and the template `.rs` files
+//! that get copied into `exercises/`.
+//!
+//! ```text
+//! catalog/
+//! ├── pack.toml
+//! └── chapter01/
+//!     ├── chapter.toml
+//!     ├── ex01.rs
+//!     └── ex02.rs
+//! ```
+//!
+//! The built-in chapters are the `catalog/` pack. Extra packs are picked up
+//! from the directories listed in the config (see [`crate::config`]), and
+//! their exercise ids are namespaced with the pack name - `acme/1.1` never
+//! collides with the built-in `1.1`.
+//!
+//! An exercise's real test suite can live in its own file (`tests = ...`).
+//! It never ends up in the learner's copy; `check` splices it into the
+//! build instead, so the learner file only carries example tests.
+//!
+//! A reference solution (`solution = ...`) stays in the catalog too, until
+//! the learner asks for it with the `solution` command.
+//!
+//! `hints = [...]` go from a nudge towards the right concept, to the API
+//! to use, to a near-solution snippet. `hint` reveals one per call.
+//!
+//! Blocking calls in async code are reported as warnings, `lint = "deny"`
+//! makes them fail the check and `lint = "off"` skips looking.
+//!
+//! Tests that legitimately run long can raise the per-test time limit with
+//! `test_timeout_secs = 30`.
+//!
+//! `trace = ["dance", "download_file(name)"]` has `check` record when those
+//! functions start and finish, so the tests can check what overlapped.
+//!
+//! `[[exercise.timeline]]` entries (a `label` and a `run` expression) are
+//! the variants the `timeline` command charts.
+//!
+//! Exercises can list prerequisites with `requires = ["1.2"]`. Ids without a
+//! `pack/` prefix refer to the same pack; `async-book/1.2` reaches into the
+//! built-in chapters from another pack.
+//!
+//! Adding an exercise is just a matter of dropping in a template and an
+//! `[[exercise]]` entry - no Rust code changes needed.
+
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use colored::*;
+
+use super::lint::LintLevel;
+use super::Exercise;
+
+/// Where the built-in chapters live
+pub const CATALOG_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/catalog");
+
+/// Name of the built-in pack - its ids are not namespaced
+pub const BUILTIN_PACK: &str = "async-book";
+
+/// Name of the manifest file at the root of a pack
+const PACK_MANIFEST: &str = "pack.toml";
+
+/// Name of the manifest file inside each chapter directory
+const CHAPTER_MANIFEST: &str = "chapter.toml";
+
+/// Contents of a `pack.toml`
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackManifest {
+    pub name: String,
+    pub title: String,
+}
+
+/// Contents of a `chapter.toml`
+#[derive(Debug, Deserialize)]
+pub struct ChapterManifest {
+    pub number: u8,
+    pub title: String,
+    #[serde(default, rename = "exercise")]
+    pub exercises: Vec<ExerciseManifest>,
+}
+
+/// A single `[[exercise]]` entry
+#[derive(Debug, Deserialize)]
+pub struct ExerciseManifest {
+    pub number: u8,
+    pub title: String,
+    /// Template file, relative to the chapter directory
+    pub template: PathBuf,
+    /// Test module spliced in by `check`, kept out of the learner's file
+    pub tests: Option<PathBuf>,
+    /// Reference solution, only ever shown by `solution`
+    pub solution: Option<PathBuf>,
+    /// Exercises that must be completed before this one unlocks
+    #[serde(default)]
+    pub requires: Vec<String>,
+    /// Overrides the `test_timeout_secs` setting for slow test suites
+    pub test_timeout_secs: Option<u64>,
+    /// Functions whose runs the tests can trace, see `instrument`
+    #[serde(default)]
+    pub trace: Vec<String>,
+    /// Variants `timeline` charts side by side
+    #[serde(default)]
+    pub timeline: Vec<TimelineVariant>,
+    /// Hints revealed one at a time by `hint`, vaguest first
+    #[serde(default)]
+    pub hints: Vec<String>,
+    /// Whether blocking code in async functions fails the check
+    #[serde(default)]
+    pub lint: LintLevel,
+}
+
+/// A way of running an exercise to chart with `timeline`
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimelineVariant {
+    pub label: String,
+    /// Rust expression for the future to run, e.g. `download_concurrent()`
+    pub run: String,
+}
+
+/// Build the id an exercise is known by, namespaced by pack
+pub fn qualify_id(pack: &str, local_id: &str) -> String {
+    if pack == BUILTIN_PACK {
+        local_id.to_string()
+    } else {
+        format!("{}/{}", pack, local_id)
+    }
+}
+
+/// Resolve a `requires` entry written inside `pack` to a full exercise id
+fn qualify_requirement(pack: &str, requirement: &str) -> String {
+    match requirement.split_once('/') {
+        Some((other_pack, local_id)) => qualify_id(other_pack, local_id),
+        None => qualify_id(pack, requirement),
+    }
+}
+
+/// Find every pack in `dirs`
+///
+/// Each directory may either be a pack itself or contain packs one level
+/// down. Packs with invalid or duplicate names are skipped with a warning.
+pub fn discover_packs(dirs: &[PathBuf]) -> Vec<(PackManifest, PathBuf)> {
+    let mut candidates = vec![];
+
+    for dir in dirs {
+        if dir.join(PACK_MANIFEST).is_file() {
+            candidates.push(dir.clone());
+        } else if let Ok(entries) = fs::read_dir(dir) {
+            let mut subdirs: Vec<PathBuf> = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.join(PACK_MANIFEST).is_file())
+                .collect();
+            subdirs.sort();
+            candidates.extend(subdirs);
+        } else {
+            eprintln!("{} {}", "⚠️  Pack directory not found:".bright_yellow(), dir.display());
+        }
+    }
+
+    let mut packs: Vec<(PackManifest, PathBuf)> = vec![];
+
+    for dir in candidates {
+        let Some(pack) = read_manifest::<PackManifest>(&dir.join(PACK_MANIFEST)) else {
+            continue;
+        };
+
+        let valid_name = !pack.name.is_empty()
+            && pack.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        if !valid_name {
+            eprintln!("{} {}: names may only use letters, digits, '-' and '_'",
+                "⚠️  Skipping pack".bright_yellow(), pack.name);
+        } else if pack.name == BUILTIN_PACK || packs.iter().any(|(p, _)| p.name == pack.name) {
+            eprintln!("{} {}: a pack with that name is already loaded",
+                "⚠️  Skipping pack".bright_yellow(), dir.display());
+        } else {
+            packs.push((pack, dir));
+        }
+    }
+
+    packs
+}
+
+/// Load every chapter of the pack rooted at `dir`, sorted by chapter and exercise number
+pub fn load_pack(pack: &PackManifest, dir: &Path) -> Vec<Exercise> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut exercises = vec![];
+
+    for entry in entries.flatten() {
+        let manifest_path = entry.path().join(CHAPTER_MANIFEST);
+        if manifest_path.is_file() {
+            exercises.extend(load_chapter(pack, &manifest_path));
+        }
+    }
+
+    exercises.sort_by_key(|ex| (ex.chapter, ex.number));
+    exercises
+}
+
+/// Load the built-in chapters shipped in `catalog/`
+pub fn load_builtin() -> Vec<Exercise> {
+    let dir = Path::new(CATALOG_DIR);
+    match read_manifest::<PackManifest>(&dir.join(PACK_MANIFEST)) {
+        Some(pack) => load_pack(&pack, dir),
+        None => vec![],
+    }
+}
+
+/// Load the exercises of a single chapter manifest
+fn load_chapter(pack: &PackManifest, manifest_path: &Path) -> Vec<Exercise> {
+    let chapter_dir = manifest_path.parent().unwrap_or(Path::new("."));
+
+    let Some(manifest) = read_manifest::<ChapterManifest>(manifest_path) else {
+        return vec![];
+    };
+
+    manifest
+        .exercises
+        .into_iter()
+        .map(|ex| Exercise {
+            id: qualify_id(&pack.name, &format!("{}.{}", manifest.number, ex.number)),
+            title: ex.title,
+            pack: pack.name.clone(),
+            pack_title: pack.title.clone(),
+            chapter: manifest.number,
+            chapter_title: manifest.title.clone(),
+            number: ex.number,
+            template: chapter_dir.join(ex.template),
+            tests: ex.tests.map(|tests| chapter_dir.join(tests)),
+            solution: ex.solution.map(|solution| chapter_dir.join(solution)),
+            requires: ex.requires.iter().map(|req| qualify_requirement(&pack.name, req)).collect(),
+            test_timeout_secs: ex.test_timeout_secs,
+            trace: ex.trace,
+            timeline: ex.timeline,
+            hints: ex.hints,
+            lint: ex.lint,
+        })
+        .collect()
+}
+
+/// Parse a TOML manifest, warning (rather than failing) when it's broken
+fn read_manifest<T: serde::de::DeserializeOwned>(path: &Path) -> Option<T> {
+    match fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| toml::from_str(&content).map_err(|e| e.to_string()))
+    {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            eprintln!("{} {}: {}", "⚠️  Skipping manifest".bright_yellow(), path.display(), e);
+            None
+        }
+    }
+}
@@ -2,98 +2,273 @@
 //!
 //! This module handles exercise generation, validation, and progression.
 //! Each exercise is generated on-demand to keep the workspace clean.
+//!
+//! The exercises themselves are data, not code - see [`manifest`] for the
+//! on-disk layout under `catalog/` and how extra packs are loaded.
 
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::io::{self, Write};
-use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
 use colored::*;
-
-pub mod chapter01;
-pub mod chapter02;
-pub mod chapter03;
-pub mod chapter04;
-pub mod chapter05;
+use serde::{Deserialize, Serialize};
+use crate::config;
+use crate::render;
+
+pub mod backup;
+pub mod coaching;
+pub mod diff;
+pub mod history;
+pub mod instrument;
+pub mod lint;
+pub mod manifest;
+pub mod results;
+pub mod runner;
+pub mod spinner;
+pub mod timeline;
+pub mod watcher;
+pub mod workspace;
 
 #[derive(Debug, Clone)]
 pub struct Exercise {
     pub id: String,
     pub title: String,
+    /// Pack the exercise comes from (`async-book` for the built-in chapters)
+    pub pack: String,
+    pub pack_title: String,
     pub chapter: u8,
+    pub chapter_title: String,
     pub number: u8,
-    pub generate: fn() -> String,
+    /// Template file the learner's copy is generated from
+    pub template: PathBuf,
+    /// Hidden test suite, added only when checking
+    pub tests: Option<PathBuf>,
+    /// Reference solution, shown on request
+    pub solution: Option<PathBuf>,
+    /// Ids of the exercises that have to be completed first
+    pub requires: Vec<String>,
+    /// Per-test time limit, when the exercise needs more than the default
+    pub test_timeout_secs: Option<u64>,
+    /// Functions instrumented for tracing when checking
+    pub trace: Vec<String>,
+    /// Variants to chart with `timeline`
+    pub timeline: Vec<manifest::TimelineVariant>,
+    /// Hints in the order `hint` reveals them
+    pub hints: Vec<String>,
+    /// What `check` does about blocking code in async functions
+    pub lint: lint::LintLevel,
+}
+
+/// Where an exercise stands given the completed ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExerciseState {
+    Completed,
+    Unlocked,
+    Locked,
+}
+
+impl Exercise {
+    /// Path of the learner's copy of this exercise
+    ///
+    /// Built-in exercises go straight into `exercises/`, pack exercises
+    /// get a sub-directory named after their pack.
+    pub fn filename(&self) -> String {
+        format!("exercises/{}.rs", self.relative_stem())
+    }
+
+    /// The exercise file's path inside `exercises/`, without the extension,
+    /// e.g. `ch01_ex01` or `my-pack/ch01_ex01`
+    ///
+    /// Backups, history and the check workspace lay themselves out by it.
+    pub fn relative_stem(&self) -> String {
+        if self.pack == manifest::BUILTIN_PACK {
+            format!("ch{:02}_ex{:02}", self.chapter, self.number)
+        } else {
+            format!("{}/ch{:02}_ex{:02}", self.pack, self.chapter, self.number)
+        }
+    }
+
+    /// Prerequisites that haven't been completed yet
+    pub fn missing_prerequisites(&self, completed: &[String]) -> Vec<String> {
+        self.requires.iter().filter(|req| !completed.contains(req)).cloned().collect()
+    }
+
+    /// Whether there's anything to solve yet - without a hidden test
+    /// suite, the template passes `check` as it is
+    pub fn is_placeholder(&self) -> bool {
+        self.tests.is_none()
+    }
+
+    pub fn state(&self, completed: &[String]) -> ExerciseState {
+        if completed.contains(&self.id) {
+            ExerciseState::Completed
+        } else if self.missing_prerequisites(completed).is_empty() {
+            ExerciseState::Unlocked
+        } else {
+            ExerciseState::Locked
+        }
+    }
 }
 
-/// Get all available exercises
-pub fn get_all_exercises() -> Vec<Exercise> {
-    let mut exercises = vec![];
+static CATALOG: OnceLock<Vec<Exercise>> = OnceLock::new();
+
+/// Get all available exercises, built-in chapters first, then any packs
+///
+/// The manifests are read (and any pack warnings printed) once per run.
+pub fn get_all_exercises() -> &'static [Exercise] {
+    CATALOG.get_or_init(|| {
+        let mut exercises = manifest::load_builtin();
 
-    exercises.extend(chapter01::get_exercises());
-    exercises.extend(chapter02::get_exercises());
-    exercises.extend(chapter03::get_exercises());
-    exercises.extend(chapter04::get_exercises());
-    exercises.extend(chapter05::get_exercises());
+        for (pack, dir) in manifest::discover_packs(&config::load_config().pack_dirs) {
+            exercises.extend(manifest::load_pack(&pack, &dir));
+        }
+
+        exercises
+    })
+}
 
-    exercises
+/// The exercise with this id, if there is one
+pub fn find_exercise(id: &str) -> Option<&'static Exercise> {
+    get_all_exercises().iter().find(|ex| ex.id == id)
 }
 
 /// List all exercises with their status
-pub fn list_exercises(completed: &[String], current: &Option<String>) {
-    // Clear screen for consistent presentation
-    print!("\x1B[2J\x1B[1;1H");
-    
-    // Title
-    println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-    println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-    println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
-    println!();
+/// `tests_passed` maps exercise ids to how many of their tests have passed
+/// so far, shown next to exercises that aren't completed yet
+pub fn list_exercises(completed: &[String], current: &Option<String>, tests_passed: &BTreeMap<String, (usize, usize)>) {
+    render::screen();
     
+    let exercises = get_all_exercises();
+    let has_packs = exercises.iter().any(|ex| ex.pack != manifest::BUILTIN_PACK);
+
     println!("{}", "📚 Exercise List".bright_yellow().bold());
-    println!("   Complete all 10 exercises to master async Rust!");
-    println!();
+    println!("   Complete all {} exercises to master async Rust!", exercises.len());
+    println!("   {} completed  {} current  {} unlocked  🔒 locked",
+        "✓".bright_green(), "→".bright_cyan(), "○".bright_white());
+
+    let mut packs: Vec<&str> = vec![];
+    for ex in exercises {
+        if !packs.contains(&ex.pack.as_str()) {
+            packs.push(&ex.pack);
+        }
+    }
 
-    let exercises = get_all_exercises();
-    let mut current_chapter = 0;
+    for pack in packs {
+        let pack_exercises: Vec<&Exercise> = exercises.iter().filter(|ex| ex.pack == pack).collect();
 
-    for ex in &exercises {
-        if ex.chapter != current_chapter {
-            current_chapter = ex.chapter;
-            if current_chapter > 1 {
-                println!();
+        println!();
+        if has_packs {
+            println!("{}", format!("📦 {} ({})", pack_exercises[0].pack_title, pack).bright_magenta().bold());
+        }
+
+        // Each exercise hangs off its first prerequisite from the same pack,
+        // anything else it needs is noted next to it
+        let mut children: Vec<Vec<usize>> = vec![vec![]; pack_exercises.len()];
+        let mut roots = vec![];
+        for (index, ex) in pack_exercises.iter().enumerate() {
+            let parent = ex.requires.iter()
+                .find_map(|req| pack_exercises.iter().position(|other| &other.id == req));
+            match parent {
+                Some(parent) => children[parent].push(index),
+                None => roots.push(index),
             }
-            println!("   {}", format!("Chapter {}", current_chapter).bright_yellow().bold());
         }
 
-        let status = if completed.contains(&ex.id) {
-            "✓".bright_green()
-        } else if Some(&ex.id) == current.as_ref() {
-            "→".bright_cyan()
-        } else {
-            " ".normal()
-        };
+        let mut printed = vec![false; pack_exercises.len()];
+        for root in roots {
+            print_tree_node(&pack_exercises, &children, root, "", "", completed, current, tests_passed, &mut printed);
+        }
 
-        println!("   {} {} - {}", status, ex.id.bright_white(), ex.title);
+        // Whatever is left is stuck in a prerequisite cycle
+        for index in 0..pack_exercises.len() {
+            if !printed[index] {
+                print_tree_node(&pack_exercises, &children, index, "", "", completed, current, tests_passed, &mut printed);
+            }
+        }
     }
     
     println!();
-    println!("{}", "─────────────────────────────────────────────────".bright_black());
-    println!();
+    render::separator();
     
     // Show next action
     if let Some(current_id) = current {
         if let Some(exercise) = exercises.iter().find(|e| &e.id == current_id) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-            if Path::new(&filename).exists() {
-                println!("   {} to test Exercise {}", "cargo run -- check".bright_green().bold(), current_id);
+            if Path::new(&exercise.filename()).exists() {
+                render::next_action("cargo run -- check", &format!("to test Exercise {}", current_id));
             } else {
-                println!("   {} to generate Exercise {}", "cargo run -- next".bright_green().bold(), current_id);
+                render::next_action("cargo run -- next", &format!("to generate Exercise {}", current_id));
             }
         }
     } else if completed.is_empty() {
-        println!("   {} to begin!", "cargo run -- next".bright_green().bold());
+        render::next_action("cargo run -- next", "to begin!");
+    }
+}
+
+/// Print an exercise and everything that depends on it as a tree
+#[allow(clippy::too_many_arguments)]
+fn print_tree_node(
+    exercises: &[&Exercise],
+    children: &[Vec<usize>],
+    index: usize,
+    prefix: &str,
+    connector: &str,
+    completed: &[String],
+    current: &Option<String>,
+    tests_passed: &BTreeMap<String, (usize, usize)>,
+    printed: &mut [bool],
+) {
+    printed[index] = true;
+    let ex = exercises[index];
+
+    let (status, title) = match ex.state(completed) {
+        ExerciseState::Completed => ("✓".bright_green(), ex.title.normal()),
+        _ if Some(&ex.id) == current.as_ref() => ("→".bright_cyan(), ex.title.normal()),
+        ExerciseState::Unlocked => ("○".bright_white(), ex.title.normal()),
+        ExerciseState::Locked => ("🔒".normal(), ex.title.bright_black()),
+    };
+
+    // Partial credit for exercises on their way to passing
+    let partial = match tests_passed.get(&ex.id) {
+        Some((passed, total)) if *passed > 0 && ex.state(completed) != ExerciseState::Completed => {
+            format!(" ({}/{} tests)", passed, total)
+        }
+        _ => String::new(),
+    };
+
+    let parent = ex.requires.iter().find_map(|req| exercises.iter().find(|other| &other.id == req));
+    let also_needs: Vec<&str> = ex.requires.iter()
+        .filter(|req| Some(*req) != parent.map(|p| &p.id))
+        .map(|req| req.as_str())
+        .collect();
+    let also_needs = if also_needs.is_empty() {
+        String::new()
+    } else {
+        format!(" (also needs {})", also_needs.join(", "))
+    };
+
+    // Mark where each chapter starts
+    let chapter = if parent.map(|p| p.chapter) != Some(ex.chapter) {
+        format!("  · Chapter {}: {}", ex.chapter, ex.chapter_title)
+    } else {
+        String::new()
+    };
+
+    println!("   {}{}{} {} - {}{}{}{}", prefix.bright_black(), connector.bright_black(),
+        status, ex.id.bright_white(), title, partial.bright_yellow(), also_needs.bright_black(), chapter.bright_yellow());
+
+    let child_prefix = match connector {
+        "" => prefix.to_string(),
+        "└── " => format!("{}    ", prefix),
+        _ => format!("{}│   ", prefix),
+    };
+
+    let pending: Vec<usize> = children[index].iter().copied().filter(|&child| !printed[child]).collect();
+    for (position, &child) in pending.iter().enumerate() {
+        let connector = if position + 1 == pending.len() { "└── " } else { "├── " };
+        print_tree_node(exercises, children, child, &child_prefix, connector, completed, current, tests_passed, printed);
     }
-    
-    println!();
 }
 
 /// Get total number of exercises
@@ -103,143 +278,368 @@ pub fn get_total_exercises() -> usize {
 
 /// Check if an exercise ID is valid
 pub fn is_valid_exercise(id: &str) -> bool {
-    get_all_exercises().iter().any(|ex| ex.id == id)
+    find_exercise(id).is_some()
+}
+
+/// Get every exercise whose prerequisites are all completed
+pub fn get_unlocked_exercises(completed: &[String]) -> Vec<String> {
+    get_all_exercises()
+        .iter()
+        .filter(|ex| ex.state(completed) == ExerciseState::Unlocked)
+        .map(|ex| ex.id.clone())
+        .collect()
 }
 
 /// Get the next exercise based on completed ones
 pub fn get_next_exercise(completed: &[String]) -> Option<String> {
-    let all_exercises = get_all_exercises();
+    get_unlocked_exercises(completed).into_iter().next()
+}
 
-    for ex in all_exercises {
-        if !completed.contains(&ex.id) {
-            return Some(ex.id);
-        }
+/// What happened when generating an exercise file
+#[derive(Debug)]
+pub enum Generated {
+    /// Fresh copy of the template (or the file still matched it)
+    Written,
+    /// The learner already changed the file, so it was left alone
+    KeptExisting,
+    /// Forced regeneration - the learner's version was backed up first
+    Regenerated(backup::Backup),
+}
+
+/// Generate exercise file
+///
+/// An existing file that differs from the template is never overwritten
+/// unless `force` is set, and even then it's backed up first.
+pub fn generate_exercise(id: &str, force: bool) -> Option<Generated> {
+    let exercise = find_exercise(id)?;
+
+    // Generate the exercise file
+    let filename = exercise.filename();
+    if let Some(dir) = Path::new(&filename).parent() {
+        // Create exercises directory if it doesn't exist
+        fs::create_dir_all(dir).ok();
     }
+    let content = fs::read_to_string(&exercise.template).expect("Failed to read exercise template");
 
-    None
+    let generated = match fs::read_to_string(&filename) {
+        Ok(existing) if existing != content => {
+            if !force {
+                return Some(Generated::KeptExisting);
+            }
+            let backup = backup::create_backup(exercise).expect("Failed to back up exercise file");
+            Generated::Regenerated(backup)
+        }
+        _ => Generated::Written,
+    };
+
+    fs::write(&filename, content).expect("Failed to write exercise file");
+    Some(generated)
 }
 
-/// Generate exercise file
-pub fn generate_exercise(id: &str) {
-    let exercises = get_all_exercises();
+/// How a `check` of an exercise went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckOutcome {
+    Passed,
+    TestsFailed,
+    BuildFailed,
+    /// A test was still running at the time limit and got killed
+    Hung,
+    /// No exercise file (or no such exercise) to check
+    Missing,
+    /// The check workspace couldn't be written, so nothing was built
+    SetupFailed,
+}
 
-    if let Some(exercise) = exercises.iter().find(|ex| ex.id == id) {
-        // Create exercises directory if it doesn't exist
-        fs::create_dir_all("exercises").ok();
+impl CheckOutcome {
+    pub fn passed(self) -> bool {
+        self == CheckOutcome::Passed
+    }
+}
 
-        // Generate the exercise file
-        let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-        let content = (exercise.generate)();
+/// Everything a `check` found out
+#[derive(Debug)]
+pub struct CheckReport {
+    pub outcome: CheckOutcome,
+    /// Per-test results, empty when the tests never got to run
+    pub tests: Vec<results::TestResult>,
+    /// What the lint found in the learner's file
+    pub findings: Vec<lint::Finding>,
+    /// The compiler's errors, when the build failed
+    pub errors: Vec<coaching::Diagnostic>,
+    /// The learner's file exactly as it was built, `None` when nothing was
+    pub code: Option<String>,
+}
 
-        fs::write(&filename, content).expect("Failed to write exercise file");
+impl CheckReport {
+    fn new(outcome: CheckOutcome, tests: Vec<results::TestResult>) -> Self {
+        CheckReport { outcome, tests, findings: vec![], errors: vec![], code: None }
     }
 }
 
 /// Check if exercise passes all tests
-pub fn check_exercise(id: &str) -> bool {
-    let exercises = get_all_exercises();
+///
+/// With `--format json` nothing is printed - it's all in the report.
+pub fn check_exercise(id: &str) -> CheckReport {
+    let Some(exercise) = find_exercise(id) else {
+        return CheckReport::new(CheckOutcome::Missing, vec![]);
+    };
+    let filename = exercise.filename();
+
+    if !Path::new(&filename).exists() {
+        eprintln!("{}", "I can't find that exercise file. Try running the exercise first!".bright_red());
+        return CheckReport::new(CheckOutcome::Missing, vec![]);
+    }
+
+    let show = !render::json();
+    if show {
+        println!("{}", "🔧 Let me compile and test your solution...".bright_yellow());
+    }
+
+    // Bring the exercise's binary in the shared check workspace up to date
+    let prepared = match workspace::prepare(exercise) {
+        Ok(prepared) => prepared,
+        Err(e) => {
+            print_setup_error(&e);
+            return CheckReport::new(CheckOutcome::SetupFailed, vec![]);
+        }
+    };
+
+    // Blocking calls can slip past timing tests, so look for them first
+    let findings = match exercise.lint {
+        lint::LintLevel::Off => vec![],
+        level => {
+            let findings = lint::lint(&prepared.code);
+            if show {
+                lint::print_findings(&findings, &filename, level);
+            }
+            findings
+        }
+    };
+    let denied = exercise.lint == lint::LintLevel::Deny && !findings.is_empty();
 
-    if let Some(exercise) = exercises.iter().find(|ex| ex.id == id) {
-        let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
+    let report = build_and_test(exercise, &prepared.bin, &filename, denied, show);
+    CheckReport { findings, code: Some(prepared.code), ..report }
+}
 
-        if !Path::new(&filename).exists() {
-            eprintln!("{}", "I can't find that exercise file. Try running the exercise first!".bright_red());
-            return false;
+/// The build and test part of `check_exercise`, printing as it goes if `show`
+fn build_and_test(exercise: &Exercise, bin: &str, filename: &str, denied: bool, show: bool) -> CheckReport {
+    let config = config::load_config();
+    let build_limit = config.build_timeout();
+    let test_limit = exercise.test_timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or_else(|| config.test_timeout());
+
+    // First try to build - this will catch any todo!() macros or compilation errors.
+    // Errors are shown as the compiler finds them, pointing at the learner's file
+    let source = workspace::source_file(bin);
+    let show_error = |error: &coaching::Diagnostic| {
+        if show {
+            coaching::print_diagnostics(std::slice::from_ref(error), &source, filename);
         }
+    };
+    let Some(build_output) = runner::run_build(bin, build_limit, show_error) else {
+        if show {
+            print_build_timeout(build_limit);
+        }
+        return CheckReport::new(CheckOutcome::BuildFailed, vec![]);
+    };
+
+    if !build_output.status.success() {
+        let diagnostics = coaching::parse(&build_output.stdout);
+        if show {
+            println!("{}", "❌ Your code doesn't compile yet.".bright_red());
+            coaching::print_coaching(&diagnostics, &source, filename);
+        }
+        let errors = diagnostics.into_iter().filter(|d| d.level == "error").collect();
+        return CheckReport { errors, ..CheckReport::new(CheckOutcome::BuildFailed, vec![]) };
+    }
 
-        // Create a temporary test project
-        println!("{}", "🔧 Let me compile and test your solution...".bright_yellow());
+    // If it builds, run the tests. Should they not compile, the errors show
+    // up as they're found, same as for the build
+    let test_run = runner::run_tests(bin, &[], build_limit, test_limit, |line| {
+        if show {
+            eprintln!("{}", line.replace(&source, filename));
+        }
+    });
 
-        // Create temporary Cargo.toml for the exercise
-        let temp_dir = "target/exercise_test";
-        fs::create_dir_all(temp_dir).ok();
-
-        let cargo_toml = format!(r#"[package]
-name = "exercise_test"
-version = "0.1.0"
-edition = "2021"
-
-[[bin]]
-name = "exercise"
-path = "exercise.rs"
-
-[dependencies]
-tokio = {{ version = "1.0", features = ["full", "test-util"] }}
-futures = "0.3"
-"#);
-
-        fs::write(format!("{}/Cargo.toml", temp_dir), cargo_toml).ok();
-        fs::copy(&filename, format!("{}/exercise.rs", temp_dir)).ok();
-
-        // First try to build - this will catch any todo!() macros or compilation errors
-        let build_output = Command::new("cargo")
-            .args(&["build", "--manifest-path", &format!("{}/Cargo.toml", temp_dir)])
-            .output()
-            .expect("Failed to run cargo build");
-
-        if !build_output.status.success() {
-            println!("{}", "❌ Your code doesn't compile yet:".bright_red());
-            // Show both stdout and stderr for better error visibility
-            if !build_output.stdout.is_empty() {
-                println!("{}", String::from_utf8_lossy(&build_output.stdout));
+    match &test_run.timeout {
+        Some(runner::Timeout::Build) => {
+            if show {
+                print_build_timeout(build_limit);
             }
-            if !build_output.stderr.is_empty() {
-                eprintln!("{}", String::from_utf8_lossy(&build_output.stderr));
+            return CheckReport::new(CheckOutcome::BuildFailed, vec![]);
+        }
+        Some(runner::Timeout::Test(_)) => {
+            if show {
+                print_test_checklist(&test_run.tests, exercise.tests.is_some());
+                if let Some(hung) = test_run.tests.iter().find(|t| t.status == results::TestStatus::Hung) {
+                    print_hang_hint(hung, test_limit);
+                }
             }
-            io::stdout().flush().ok();
-            io::stderr().flush().ok();
-            
-            // Clean up
-            fs::remove_dir_all(temp_dir).ok();
-            return false;
+            return CheckReport::new(CheckOutcome::Hung, test_run.tests);
         }
+        None => {}
+    }
 
-        // If it builds, run the tests
-        let test_output = Command::new("cargo")
-            .args(&["test", "--manifest-path", &format!("{}/Cargo.toml", temp_dir)])
-            .output()
-            .expect("Failed to run cargo test");
+    // Every hidden test has to report back. A test that exits the process,
+    // or code that keeps the suite out of the test build, would otherwise
+    // end the run "successfully" without anything failing
+    if test_run.status.success() && exercise.tests.is_some() {
+        let listed = runner::run_list(bin);
+        let unreported = results::unreported_hidden_tests(&listed, &test_run.tests);
+        let suite_ran = listed.iter().any(|name| results::is_hidden(name)) && unreported.is_empty();
+        if !suite_ran {
+            let mut tests = test_run.tests;
+            tests.extend(unreported.iter().map(|name| results::TestResult::not_run(name)));
+            if show && !tests.is_empty() {
+                print_test_checklist(&tests, true);
+            }
+            if show {
+                println!("{}", "❌ The test suite didn't run to the end. Make sure your code doesn't exit the process or keep the tests out of the build.".bright_red());
+            }
+            return CheckReport::new(CheckOutcome::TestsFailed, tests);
+        }
+    }
 
-        // Clean up
-        fs::remove_dir_all(temp_dir).ok();
+    if test_run.tests.is_empty() {
+        // Nothing ran - most likely the tests themselves didn't compile,
+        // unless this is a placeholder without any tests
+        if test_run.status.success() {
+            return passed_unless_denied(denied, vec![], show);
+        }
 
-        if test_output.status.success() {
-            println!("{}", "✅ Excellent! All your tests are passing!".bright_green());
-            true
-        } else {
-            println!("{}", "❌ Your tests aren't quite there yet:".bright_red());
-            // Show both stdout and stderr for test output
-            if !test_output.stdout.is_empty() {
-                println!("{}", String::from_utf8_lossy(&test_output.stdout));
-            }
-            if !test_output.stderr.is_empty() {
-                eprintln!("{}", String::from_utf8_lossy(&test_output.stderr));
+        // Whatever cargo had to say is already on screen
+        if show {
+            println!("{}", "❌ Your tests aren't quite there yet.".bright_red());
+            if !test_run.stdout.is_empty() {
+                println!("{}", test_run.stdout);
             }
             io::stdout().flush().ok();
-            io::stderr().flush().ok();
-            false
         }
+        return CheckReport::new(CheckOutcome::TestsFailed, vec![]);
+    }
+
+    if show {
+        print_test_checklist(&test_run.tests, exercise.tests.is_some());
+    }
+
+    if test_run.status.success() {
+        passed_unless_denied(denied, test_run.tests, show)
     } else {
-        false
+        if show {
+            println!("{}", "❌ Your tests aren't quite there yet.".bright_red());
+            // A warning (like a future that's never awaited) can be the reason why
+            let warnings = coaching::parse(&build_output.stdout);
+            coaching::print_coaching(&warnings, &source, filename);
+        }
+        CheckReport::new(CheckOutcome::TestsFailed, test_run.tests)
     }
 }
 
+/// The report for passing tests - unless the lint found blocking code in an
+/// exercise that doesn't allow it
+fn passed_unless_denied(denied: bool, tests: Vec<results::TestResult>, show: bool) -> CheckReport {
+    if denied {
+        if show {
+            println!("{}", "❌ The tests pass, but the blocking code above has to go first.".bright_red());
+        }
+        return CheckReport::new(CheckOutcome::TestsFailed, tests);
+    }
+
+    if show && tests.is_empty() {
+        println!("{}", "✅ It compiles, and there are no tests to run.".bright_green());
+    } else if show {
+        println!("{}", "✅ Excellent! All your tests are passing!".bright_green());
+    }
+    CheckReport::new(CheckOutcome::Passed, tests)
+}
+
+/// Show every test with a tick or a cross, and why the failing ones failed
+///
+/// With a hidden test suite (`hidden_suite`) the learner's own tests are
+/// listed too, but only the suite's count in the total.
+fn print_test_checklist(tests: &[results::TestResult], hidden_suite: bool) {
+    let (passed, total) = results::tally(tests, hidden_suite);
+
+    println!();
+    println!("{} {}", "🧪 Test Results".bright_yellow().bold(),
+        format!("{}/{} passing", passed, total).bright_white());
+
+    for test in tests {
+        let duration = test.duration_ms
+            .map(|ms| format!(" ({:.2}s)", ms as f64 / 1000.0))
+            .unwrap_or_default();
+
+        match test.status {
+            results::TestStatus::Passed => {
+                println!("   {} {}{}", "✓".bright_green(), test.display_name(), duration.bright_black());
+            }
+            results::TestStatus::Failed => {
+                println!("   {} {}{}", "✗".bright_red(), test.display_name().bright_white(), duration.bright_black());
+                if let Some(message) = &test.panic_message {
+                    for line in message.lines() {
+                        println!("       {}", line.bright_red());
+                    }
+                }
+            }
+            results::TestStatus::Ignored => {
+                println!("   {} {} {}", "○".bright_black(), test.display_name().bright_black(), "(ignored)".bright_black());
+            }
+            results::TestStatus::Hung => {
+                println!("   {} {} {}", "⏳".bright_yellow(), test.display_name().bright_white(),
+                    "(hung - stopped)".bright_yellow());
+            }
+            results::TestStatus::NotRun => {
+                println!("   {} {} {}", "○".bright_black(), test.display_name().bright_black(), "(didn't run)".bright_black());
+            }
+        }
+    }
+
+    println!();
+}
+
+/// Why there's nothing to build - on stderr, so it shows even with `--format json`
+pub fn print_setup_error(error: &io::Error) {
+    eprintln!("{} {}: {}", "❌ I couldn't set up the check workspace in".bright_red(),
+        workspace::WORKSPACE_DIR.bright_white(), error);
+    eprintln!("   Make sure {} is writable and try again.", "target/".bright_white());
+}
+
+fn print_build_timeout(limit: Duration) {
+    println!("{}", format!("⏳ The build took longer than {}s, so I stopped it.", limit.as_secs()).bright_red());
+    println!("   If it's still compiling dependencies, raise {} in .async-book.toml.",
+        "build_timeout_secs".bright_white());
+}
+
+/// Explain the most likely reason a test never finished
+fn print_hang_hint(test: &results::TestResult, limit: Duration) {
+    println!("{}", format!("⏳ {} was still running after {}s, so I stopped it.",
+        test.display_name(), limit.as_secs()).bright_red());
+    println!("   Tests after it didn't get to run.");
+    println!();
+    println!("{}", "💡 Why would a test hang?".bright_yellow().bold());
+    println!("   Usually a future returned {} and nothing ever called its {}.",
+        "Poll::Pending".bright_white(), "Waker".bright_white());
+    println!("   The executor only polls again once it's woken, so make sure every");
+    println!("   path that returns Pending has arranged for {} (or",
+        "cx.waker().wake_by_ref()".bright_white());
+    println!("   a clone of the waker) to be called later. Blocking the thread with");
+    println!("   std::thread::sleep or a lock held across .await can do it too.");
+    println!();
+}
+
 /// Clean up all generated exercise files
 pub fn cleanup_all() {
     if Path::new("exercises").exists() {
         fs::remove_dir_all("exercises").ok();
     }
 
-    if Path::new("target/exercise_test").exists() {
-        fs::remove_dir_all("target/exercise_test").ok();
+    if Path::new(history::HISTORY_DIR).exists() {
+        fs::remove_dir_all(history::HISTORY_DIR).ok();
     }
-}
 
-/// Macro to check if all tests in a module pass
-#[macro_export]
-macro_rules! exercise_complete {
-    () => {{
-        // This will be replaced by our test runner
-        false
-    }};
+    // The compiled dependencies stay cached, only the exercise sources go
+    workspace::clean_sources();
 }
+
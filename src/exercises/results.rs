@@ -0,0 +1,431 @@
+//! Per-test results
+//!
+//! `check` runs the exercise tests one at a time and reads libtest's output
+//! as it arrives. With a single test thread libtest prints `test <name> ... `
+//! before each test starts and the result once it's done, which is enough
+//! to time every test and pick its panic message out of the failure report.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Module the hidden test suite is spliced in as
+const HIDDEN_TESTS_MODULE: &str = "__hidden_tests::";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Ignored,
+    /// Ran past the time limit and was killed
+    Hung,
+    /// Never got to run, because a test before it hung
+    NotRun,
+}
+
+/// The outcome of a single test
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResult {
+    /// Full test path, e.g. `__hidden_tests::test_timeout`
+    pub name: String,
+    pub status: TestStatus,
+    /// Wall-clock time the test took, when it could be measured
+    pub duration_ms: Option<u64>,
+    /// What the test panicked with, for failed tests
+    pub panic_message: Option<String>,
+}
+
+impl TestResult {
+    /// A test that never got to run
+    pub fn not_run(name: &str) -> Self {
+        TestResult { name: name.to_string(), status: TestStatus::NotRun, duration_ms: None, panic_message: None }
+    }
+
+    /// Name to show the learner - the test suite's module is an
+    /// implementation detail, the example tests keep their `tests::` path
+    pub fn display_name(&self) -> &str {
+        display_name(&self.name)
+    }
+}
+
+/// A test's name without the hidden test suite's module
+pub fn display_name(name: &str) -> &str {
+    name.strip_prefix(HIDDEN_TESTS_MODULE).unwrap_or(name)
+}
+
+/// Whether a test belongs to the hidden test suite
+pub fn is_hidden(name: &str) -> bool {
+    name.starts_with(HIDDEN_TESTS_MODULE)
+}
+
+/// Hidden tests from `listed` that have no result in `tests`
+pub fn unreported_hidden_tests(listed: &[String], tests: &[TestResult]) -> Vec<String> {
+    listed.iter()
+        .filter(|name| is_hidden(name) && !tests.iter().any(|test| &test.name == *name))
+        .cloned()
+        .collect()
+}
+
+/// Test names from libtest's `--list` output (`name: test` lines)
+pub fn parse_list(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.strip_suffix(": test"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a test counts towards passing the exercise
+///
+/// Ignored tests never do. When the exercise has a hidden test suite
+/// (`hidden_suite`), only its tests do - the learner's own tests are
+/// theirs to add and remove as they like.
+pub fn counts(test: &TestResult, hidden_suite: bool) -> bool {
+    test.status != TestStatus::Ignored && (!hidden_suite || is_hidden(&test.name))
+}
+
+/// `(passed, total)` for a set of results, counting what [`counts`]
+pub fn tally(tests: &[TestResult], hidden_suite: bool) -> (usize, usize) {
+    let counted = tests.iter().filter(|t| counts(t, hidden_suite));
+    let passed = counted.clone().filter(|t| t.status == TestStatus::Passed).count();
+    (passed, counted.count())
+}
+
+/// Incremental parser for libtest's human-readable output
+#[derive(Default)]
+pub struct LibtestParser {
+    /// Bytes of the line currently being received
+    partial: Vec<u8>,
+    started: HashMap<String, Instant>,
+    results: Vec<TestResult>,
+    /// How many tests libtest said it's going to run
+    total: Option<usize>,
+    /// Test whose captured output (`---- name stdout ----`) we're inside
+    section: Option<String>,
+    /// Panic message lines collected for the current section
+    panic_lines: Option<Vec<String>>,
+}
+
+impl LibtestParser {
+    /// Feed the next chunk of stdout as soon as it arrives
+    pub fn feed(&mut self, chunk: &[u8]) {
+        self.partial.extend_from_slice(chunk);
+
+        while let Some(newline) = self.partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.parse_line(line.trim_end_matches(['\n', '\r']));
+        }
+
+        // `test name ... ` with nothing after it yet means the test just started
+        let partial = String::from_utf8_lossy(&self.partial);
+        if let Some(name) = partial.strip_prefix("test ").and_then(|rest| rest.strip_suffix(" ... ")) {
+            self.started.entry(name.to_string()).or_insert_with(Instant::now);
+        }
+    }
+
+    fn parse_line(&mut self, line: &str) {
+        // `running 7 tests`
+        if let Some(count) = line.strip_prefix("running ").and_then(|rest| rest.split(' ').next()) {
+            if let Ok(count) = count.parse() {
+                self.total = Some(count);
+                return;
+            }
+        }
+
+        if let Some(rest) = line.strip_prefix("test ") {
+            if let Some((name, outcome)) = rest.split_once(" ... ") {
+                let status = match outcome {
+                    "ok" => Some(TestStatus::Passed),
+                    "FAILED" => Some(TestStatus::Failed),
+                    _ if outcome.starts_with("ignored") => Some(TestStatus::Ignored),
+                    _ => None,
+                };
+
+                if let Some(status) = status {
+                    let duration_ms = self.started.get(name).map(|start| start.elapsed().as_millis() as u64);
+                    self.results.push(TestResult {
+                        name: name.to_string(),
+                        status,
+                        duration_ms,
+                        panic_message: None,
+                    });
+                    return;
+                }
+            }
+        }
+
+        if let Some(name) = line.strip_prefix("---- ").and_then(|rest| rest.strip_suffix(" stdout ----")) {
+            self.close_section();
+            self.section = Some(name.to_string());
+            return;
+        }
+
+        if self.section.is_none() {
+            return;
+        }
+
+        if line == "failures:" || line.starts_with("test result:") {
+            self.close_section();
+        } else if let Some(lines) = &mut self.panic_lines {
+            // The message ends where the backtrace (or the hint about it) starts
+            if line == "stack backtrace:" || line.starts_with("note: ") {
+                self.close_section();
+            } else {
+                lines.push(line.to_string());
+            }
+        } else if let Some((_, location)) = line.split_once(" panicked at ") {
+            // Older toolchains put the message on the same line:
+            // panicked at 'message', src/main.rs:1:1
+            match location.strip_prefix('\'').and_then(|rest| rest.rsplit_once("', ")) {
+                Some((message, _)) => self.panic_lines = Some(vec![message.to_string()]),
+                None => self.panic_lines = Some(vec![]),
+            }
+        }
+    }
+
+    fn close_section(&mut self) {
+        let (Some(name), Some(lines)) = (self.section.take(), self.panic_lines.take()) else {
+            return;
+        };
+
+        let message = lines.join("\n").trim().to_string();
+        if let Some(result) = self.results.iter_mut().find(|result| result.name == name) {
+            if !message.is_empty() {
+                result.panic_message = Some(message);
+            }
+        }
+    }
+
+    /// The test that has started but not finished yet, and when it started
+    pub fn running(&self) -> Option<(&str, Instant)> {
+        self.started
+            .iter()
+            .find(|(name, _)| !self.results.iter().any(|result| &result.name == *name))
+            .map(|(name, start)| (name.as_str(), *start))
+    }
+
+    /// How many tests have finished so far
+    pub fn completed(&self) -> usize {
+        self.results.len()
+    }
+
+    /// How many tests there are in all, once libtest has said so
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Record that the running test was killed for taking too long
+    pub fn mark_hung(&mut self, name: &str) {
+        let duration_ms = self.started.get(name).map(|start| start.elapsed().as_millis() as u64);
+        self.results.push(TestResult {
+            name: name.to_string(),
+            status: TestStatus::Hung,
+            duration_ms,
+            panic_message: None,
+        });
+    }
+
+    /// Record every test in `all` that hasn't reported yet as not run, so
+    /// a hang doesn't make the suite look smaller than it is
+    pub fn mark_not_run(&mut self, all: &[String]) {
+        for name in all {
+            if !self.results.iter().any(|result| &result.name == name) {
+                self.results.push(TestResult::not_run(name));
+            }
+        }
+    }
+
+    /// All results seen, in the order the tests ran
+    pub fn finish(mut self) -> Vec<TestResult> {
+        if !self.partial.is_empty() {
+            let line = String::from_utf8_lossy(&std::mem::take(&mut self.partial)).to_string();
+            self.parse_line(&line);
+        }
+        self.close_section();
+        self.results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(output: &str) -> Vec<TestResult> {
+        let mut parser = LibtestParser::default();
+        parser.feed(output.as_bytes());
+        parser.finish()
+    }
+
+    fn statuses(results: &[TestResult]) -> Vec<(&str, TestStatus)> {
+        results.iter().map(|r| (r.name.as_str(), r.status)).collect()
+    }
+
+    const FAILED_RUN: &str = "\
+running 4 tests
+test __hidden_tests::test_joins ... ok
+test __hidden_tests::test_order ... FAILED
+test tests::test_network ... ignored, needs a network
+test __hidden_tests::timing::test_concurrent ... FAILED
+
+failures:
+
+---- __hidden_tests::test_order stdout ----
+
+thread '__hidden_tests::test_order' panicked at src/bin/ch01_ex01.rs:40:9:
+assertion `left == right` failed
+  left: 1
+ right: 2
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- __hidden_tests::timing::test_concurrent stdout ----
+thread '__hidden_tests::timing::test_concurrent' panicked at src/bin/ch01_ex01.rs:52:5:
+expected the operations to overlap
+but they ran one after another
+stack backtrace:
+   0: rust_begin_unwind
+
+failures:
+    __hidden_tests::test_order
+    __hidden_tests::timing::test_concurrent
+
+test result: FAILED. 1 passed; 2 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+
+    #[test]
+    fn reads_ok_failed_and_ignored_tests() {
+        let results = parse(FAILED_RUN);
+        assert_eq!(statuses(&results), vec![
+            ("__hidden_tests::test_joins", TestStatus::Passed),
+            ("__hidden_tests::test_order", TestStatus::Failed),
+            ("tests::test_network", TestStatus::Ignored),
+            ("__hidden_tests::timing::test_concurrent", TestStatus::Failed),
+        ]);
+        assert_eq!(tally(&results, true), (1, 3));
+        assert_eq!(results[0].panic_message, None);
+    }
+
+    #[test]
+    fn counts_only_the_hidden_suite_when_there_is_one() {
+        let results = parse("\
+running 3 tests
+test __hidden_tests::test_joins ... ok
+test tests::test_mine ... ok
+test tests::test_scratch ... FAILED
+");
+        assert_eq!(tally(&results, true), (1, 1));
+        assert_eq!(tally(&results, false), (2, 3));
+    }
+
+    #[test]
+    fn keeps_every_line_of_a_panic_message() {
+        let results = parse(FAILED_RUN);
+        assert_eq!(results[1].panic_message.as_deref(),
+            Some("assertion `left == right` failed\n  left: 1\n right: 2"));
+        assert_eq!(results[3].panic_message.as_deref(),
+            Some("expected the operations to overlap\nbut they ran one after another"));
+    }
+
+    #[test]
+    fn reads_the_message_older_toolchains_put_on_the_panic_line() {
+        let results = parse("\
+running 1 test
+test __hidden_tests::test_order ... FAILED
+
+failures:
+
+---- __hidden_tests::test_order stdout ----
+thread '__hidden_tests::test_order' panicked at 'wrong order', src/bin/ch01_ex01.rs:40:9
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+");
+        assert_eq!(results[0].panic_message.as_deref(), Some("wrong order"));
+    }
+
+    #[test]
+    fn keeps_names_with_module_paths_whole() {
+        let results = parse(FAILED_RUN);
+        assert_eq!(results[3].name, "__hidden_tests::timing::test_concurrent");
+        assert_eq!(results[3].display_name(), "timing::test_concurrent");
+        assert_eq!(results[2].display_name(), "tests::test_network");
+    }
+
+    #[test]
+    fn tracks_a_run_cut_short_by_a_kill() {
+        let mut parser = LibtestParser::default();
+        // Chunks split mid-line, the way they arrive from the pipe
+        parser.feed(b"running 3 tests\ntest __hidden_tests::test_fast ... o");
+        parser.feed(b"k\ntest __hidden_tests::test_stuck ... ");
+
+        assert_eq!(parser.total(), Some(3));
+        assert_eq!(parser.completed(), 1);
+        let (running, _) = parser.running().expect("a test should be running");
+        assert_eq!(running, "__hidden_tests::test_stuck");
+
+        parser.mark_hung("__hidden_tests::test_stuck");
+        let results = parser.finish();
+        assert_eq!(statuses(&results), vec![
+            ("__hidden_tests::test_fast", TestStatus::Passed),
+            ("__hidden_tests::test_stuck", TestStatus::Hung),
+        ]);
+        assert!(results[1].duration_ms.is_some());
+    }
+
+    #[test]
+    fn counts_the_tests_a_hang_kept_from_running() {
+        let mut parser = LibtestParser::default();
+        parser.feed(b"running 3 tests
+test __hidden_tests::test_fast ... ok
+test __hidden_tests::test_stuck ... ");
+        parser.mark_hung("__hidden_tests::test_stuck");
+        parser.mark_not_run(&parse_list("\
+__hidden_tests::test_fast: test
+__hidden_tests::test_stuck: test
+__hidden_tests::test_after: test
+
+3 tests, 0 benchmarks
+"));
+
+        let results = parser.finish();
+        assert_eq!(statuses(&results), vec![
+            ("__hidden_tests::test_fast", TestStatus::Passed),
+            ("__hidden_tests::test_stuck", TestStatus::Hung),
+            ("__hidden_tests::test_after", TestStatus::NotRun),
+        ]);
+        assert_eq!(tally(&results, true), (1, 3));
+    }
+
+    #[test]
+    fn finds_hidden_tests_that_never_reported() {
+        // A learner test that exits the process ends the run early
+        let results = parse("running 3 tests\ntest __hidden_tests::test_joins ... ok\ntest tests::aaa_exit ... ");
+        let listed = parse_list("\
+__hidden_tests::test_joins: test
+__hidden_tests::test_order: test
+tests::aaa_exit: test
+");
+
+        assert_eq!(unreported_hidden_tests(&listed, &results), vec!["__hidden_tests::test_order"]);
+        assert!(!is_hidden("tests::aaa_exit"));
+    }
+
+    #[test]
+    fn finds_nothing_missing_once_every_hidden_test_reported() {
+        let listed = parse_list("\
+__hidden_tests::test_joins: test
+__hidden_tests::test_order: test
+__hidden_tests::timing::test_concurrent: test
+tests::test_network: test
+tests::test_only_in_the_list: test
+");
+        assert!(unreported_hidden_tests(&listed, &parse(FAILED_RUN)).is_empty());
+    }
+
+    #[test]
+    fn parses_a_last_line_without_a_newline() {
+        let results = parse("running 1 test\ntest tests::test_example ... ok");
+        assert_eq!(statuses(&results), vec![("tests::test_example", TestStatus::Passed)]);
+    }
+}
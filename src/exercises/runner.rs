@@ -0,0 +1,268 @@
+//! Running cargo for a check
+//!
+//! Cargo runs as a tokio child process, so its output can be read as it
+//! arrives while a timer keeps the spinner going. What cargo is busy with
+//! shows on the spinner line, compiler errors are printed the moment
+//! they're reported, and tests are run with a single test thread so
+//! [`LibtestParser`] can time each test while it runs.
+//!
+//! Nothing cargo runs is trusted to finish. A future that is never woken
+//! doesn't crash, it just waits forever - so builds and tests both run
+//! against a wall-clock limit, and whatever blows through it is killed
+//! along with every process it started.
+
+use std::future::Future;
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::Child;
+use tokio::time;
+
+use super::coaching::{self, Diagnostic};
+use super::results::{self, LibtestParser, TestResult};
+use super::spinner::{self, Spinner};
+use super::workspace;
+
+/// Why a run had to be killed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Timeout {
+    /// Compiling took longer than the build limit
+    Build,
+    /// This test ran longer than the test limit
+    Test(String),
+}
+
+/// Everything a `cargo test` run produced
+pub struct TestRun {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub tests: Vec<TestResult>,
+    /// Set when the run was killed before it could finish
+    pub timeout: Option<Timeout>,
+}
+
+/// Spawn with piped output, in a process group of its own on Unix so the
+/// whole tree can be killed at once
+fn spawn(mut command: Command) -> Child {
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    tokio::process::Command::from(command).spawn().expect("Failed to run cargo")
+}
+
+/// Kill a child and everything it started
+///
+/// Killing only cargo would leave the test binary it runs behind, still
+/// stuck on whatever made it hang.
+async fn kill_tree(child: &mut Child) {
+    #[cfg(unix)]
+    if let Some(id) = child.id() {
+        Command::new("kill")
+            .args(["-KILL", "--", &format!("-{}", id)])
+            .stderr(Stdio::null())
+            .status()
+            .ok();
+    }
+
+    child.kill().await.ok();
+}
+
+/// Drive a run to the end on a runtime of its own - the rest of the
+/// tutorial is synchronous
+fn block_on<F: Future>(run: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to start the tokio runtime")
+        .block_on(run)
+}
+
+/// What cargo says it's doing, for lines like `   Compiling tokio v1.0.0`
+///
+/// Cargo right-aligns a capitalized verb in front of each step; errors,
+/// warnings and diagnostics never look like that.
+fn cargo_status(line: &str) -> Option<&str> {
+    let status = line.trim_start();
+    let (verb, _) = status.split_once(' ')?;
+    let is_status = line.starts_with(' ')
+        && verb.starts_with(|c: char| c.is_ascii_uppercase())
+        && verb.chars().all(|c| c.is_ascii_alphabetic());
+    // Leave off the path cargo puts after the crate, it's the check workspace's
+    is_status.then(|| status.split(" (").next().unwrap_or(status))
+}
+
+/// Names of the tests in an already built binary, e.g. to find out which
+/// ones a hang kept from running
+async fn list_tests(bin: &str, test_args: &[&str]) -> Vec<String> {
+    let mut command = workspace::cargo_command("test", bin);
+    command.args(["--", "--list"]).args(test_args.iter().filter(|arg| !arg.starts_with("--")));
+
+    match tokio::process::Command::from(command).output().await {
+        Ok(output) => results::parse_list(&String::from_utf8_lossy(&output.stdout)),
+        Err(_) => vec![],
+    }
+}
+
+fn push_line(output: &mut Vec<u8>, line: &str) {
+    output.extend_from_slice(line.as_bytes());
+    output.push(b'\n');
+}
+
+/// Build an exercise binary, giving up after `limit`
+///
+/// Diagnostics come back on stdout as JSON, see [`super::coaching`], and
+/// every error is handed to `on_error` as soon as the compiler reports it.
+/// Returns `None` when the build had to be killed.
+pub fn run_build(bin: &str, limit: Duration, on_error: impl FnMut(&Diagnostic)) -> Option<Output> {
+    block_on(build(bin, limit, on_error))
+}
+
+async fn build(bin: &str, limit: Duration, mut on_error: impl FnMut(&Diagnostic)) -> Option<Output> {
+    let mut command = workspace::cargo_command("build", bin);
+    command.arg("--message-format=json");
+
+    let mut child = spawn(command);
+    let mut stdout_lines = BufReader::new(child.stdout.take().expect("stdout is piped")).lines();
+    let mut stderr_lines = BufReader::new(child.stderr.take().expect("stderr is piped")).lines();
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let (mut stdout_open, mut stderr_open) = (true, true);
+
+    let mut spinner = Spinner::new("Building");
+    let mut frames = time::interval(spinner::INTERVAL);
+    let deadline = time::sleep(limit);
+    tokio::pin!(deadline);
+
+    while stdout_open || stderr_open {
+        tokio::select! {
+            line = stdout_lines.next_line(), if stdout_open => match line {
+                Ok(Some(line)) => {
+                    if let Some(error) = coaching::parse_line(&line).filter(|d| d.level == "error") {
+                        spinner.suspend(|| on_error(&error));
+                    }
+                    push_line(&mut stdout, &line);
+                }
+                _ => stdout_open = false,
+            },
+            line = stderr_lines.next_line(), if stderr_open => match line {
+                // Without the spinner, cargo's status lines show as they are
+                Ok(Some(line)) => {
+                    match cargo_status(&line) {
+                        Some(status) if spinner.enabled() => spinner.set_message(status),
+                        _ => spinner.suspend(|| eprintln!("{}", line)),
+                    }
+                    push_line(&mut stderr, &line);
+                }
+                _ => stderr_open = false,
+            },
+            _ = frames.tick() => spinner.tick(),
+            _ = &mut deadline => {
+                spinner.clear();
+                kill_tree(&mut child).await;
+                return None;
+            }
+        }
+    }
+
+    let status = child.wait().await.expect("Failed to wait for cargo build");
+    Some(Output { status, stdout, stderr })
+}
+
+/// Run the tests of an exercise binary in the check workspace
+///
+/// `test_args` go to the test binary, e.g. a filter. Compiling the test
+/// harness counts against `build_limit`, each test against `test_limit`
+/// from the moment it starts. Until the first test starts, whatever cargo
+/// prints besides its status lines - the errors, if the tests don't
+/// compile - is handed to `on_output` line by line.
+pub fn run_tests(bin: &str, test_args: &[&str], build_limit: Duration, test_limit: Duration,
+    on_output: impl FnMut(&str)) -> TestRun {
+    block_on(test(bin, test_args, build_limit, test_limit, on_output))
+}
+
+/// Names of every test in an exercise binary that's already been tested
+pub fn run_list(bin: &str) -> Vec<String> {
+    block_on(list_tests(bin, &[]))
+}
+
+async fn test(bin: &str, test_args: &[&str], build_limit: Duration, test_limit: Duration,
+    mut on_output: impl FnMut(&str)) -> TestRun {
+    let mut command = workspace::cargo_command("test", bin);
+    command.args(["--", "--test-threads=1"]).args(test_args);
+
+    let mut child = spawn(command);
+    let mut stdout_pipe = child.stdout.take().expect("stdout is piped");
+    let mut stderr_lines = BufReader::new(child.stderr.take().expect("stderr is piped")).lines();
+    let mut stdout = Vec::new();
+    let (mut stdout_open, mut stderr_open) = (true, true);
+    let mut buffer = [0u8; 4096];
+
+    let mut parser = LibtestParser::default();
+    let mut timeout = None;
+    let spawned = Instant::now();
+
+    let mut spinner = Spinner::new("Building the tests");
+    // The limits are checked on every frame, so a hung test - which prints
+    // nothing at all - is still noticed
+    let mut frames = time::interval(spinner::INTERVAL);
+
+    while stdout_open || stderr_open {
+        tokio::select! {
+            read = stdout_pipe.read(&mut buffer), if stdout_open => match read {
+                Ok(0) | Err(_) => stdout_open = false,
+                Ok(read) => {
+                    parser.feed(&buffer[..read]);
+                    stdout.extend_from_slice(&buffer[..read]);
+                }
+            },
+            line = stderr_lines.next_line(), if stderr_open => match line {
+                Ok(Some(line)) => {
+                    match cargo_status(&line) {
+                        Some(status) if spinner.enabled() => spinner.set_message(status),
+                        Some(_) => eprintln!("{}", line),
+                        None if parser.total().is_none() => spinner.suspend(|| on_output(&line)),
+                        None => {}
+                    }
+                }
+                _ => stderr_open = false,
+            },
+            _ = frames.tick() => {
+                if let Some((name, started)) = parser.running() {
+                    spinner.set_message(&format!("Testing {} ({}/{} done)",
+                        results::display_name(name), parser.completed(), parser.total().unwrap_or(0)));
+                    if started.elapsed() > test_limit {
+                        timeout = Some(Timeout::Test(name.to_string()));
+                    }
+                } else if stdout.is_empty() && spawned.elapsed() > build_limit {
+                    timeout = Some(Timeout::Build);
+                }
+
+                if timeout.is_some() {
+                    spinner.clear();
+                    kill_tree(&mut child).await;
+                    break;
+                }
+                spinner.tick();
+            }
+        }
+    }
+    drop(spinner);
+
+    let status = child.wait().await.expect("Failed to wait for cargo test");
+
+    if let Some(Timeout::Test(name)) = &timeout {
+        parser.mark_hung(name);
+        parser.mark_not_run(&list_tests(bin, test_args).await);
+    }
+
+    TestRun {
+        status,
+        stdout: String::from_utf8_lossy(&stdout).to_string(),
+        tests: parser.finish(),
+        timeout,
+    }
+}
@@ -0,0 +1,88 @@
+//! A one-line progress indicator for cargo runs
+//!
+//! The first `check` compiles tokio and everything else the exercises use,
+//! which takes a while. The spinner redraws a single line with whatever
+//! cargo is busy with, and steps aside whenever something has to be
+//! printed for real. It only draws on a terminal - elsewhere cargo's status
+//! lines are printed as they are - and never with `--format json`.
+
+use colored::*;
+use std::io::{self, IsTerminal, Write};
+use std::time::Duration;
+
+use crate::render;
+
+/// How often the spinner moves on to its next frame
+pub const INTERVAL: Duration = Duration::from_millis(80);
+
+const FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Longest message drawn, so the line never wraps and can always be erased
+const MAX_MESSAGE: usize = 60;
+
+pub struct Spinner {
+    enabled: bool,
+    frame: usize,
+    message: String,
+    /// Whether the spinner's line is currently on screen
+    drawn: bool,
+}
+
+impl Spinner {
+    pub fn new(message: &str) -> Self {
+        Spinner {
+            enabled: io::stdout().is_terminal() && !render::json(),
+            frame: 0,
+            message: message.to_string(),
+            drawn: false,
+        }
+    }
+
+    /// Whether the spinner draws at all
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Change what the spinner says, from the next frame on
+    pub fn set_message(&mut self, message: &str) {
+        self.message = match message.chars().count() > MAX_MESSAGE {
+            true => format!("{}…", message.chars().take(MAX_MESSAGE - 1).collect::<String>()),
+            false => message.to_string(),
+        };
+    }
+
+    /// Draw the next frame
+    pub fn tick(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        self.frame = (self.frame + 1) % FRAMES.len();
+        print!("\r\x1B[2K{} {}", FRAMES[self.frame].bright_yellow(), self.message.bright_black());
+        io::stdout().flush().ok();
+        self.drawn = true;
+    }
+
+    /// Take the spinner off the screen until the next frame
+    pub fn clear(&mut self) {
+        if self.drawn {
+            print!("\r\x1B[2K");
+            io::stdout().flush().ok();
+            self.drawn = false;
+        }
+    }
+
+    /// Print something without the spinner's line getting in the way
+    pub fn suspend(&mut self, print: impl FnOnce()) {
+        self.clear();
+        print();
+        io::stdout().flush().ok();
+        io::stderr().flush().ok();
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
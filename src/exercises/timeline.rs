@@ -0,0 +1,183 @@
+//! Timelines of when each traced operation ran
+//!
+//! `timeline` runs the variants an exercise lists (`[[exercise.timeline]]`
+//! in the manifest) on tokio's paused clock, each under
+//! `exercise_support::traced`, and charts the spans they report:
+//!
+//! ```text
+//! Sequential                                                 3.0s
+//!   learn_and_sing │████████████████████                    │ 0.0s → 1.5s
+//!   learn_song     │█████████████                           │ 0.0s → 1.0s
+//!   sing_song      │             ███████                    │ 1.0s → 1.5s
+//!   dance          │                    ████████████████████│ 1.5s → 3.0s
+//!
+//! Concurrent                                                 1.5s
+//!   learn_and_sing │████████████████████                    │ 0.0s → 1.5s
+//!   learn_song     │█████████████                           │ 0.0s → 1.0s
+//!   dance          │████████████████████                    │ 0.0s → 1.5s
+//!   sing_song      │             ███████                    │ 1.0s → 1.5s
+//!
+//!                  └────────────────────────────────────────┘
+//!                   0s                                  3.0s
+//! ```
+//!
+//! Every variant is drawn on the same scale, so the concurrent one is
+//! visibly shorter.
+
+use colored::*;
+use std::io;
+use std::time::Duration;
+
+use super::runner::{self, Timeout};
+use super::{workspace, Exercise};
+use crate::config;
+
+/// Columns the longest variant is stretched over
+const CHART_WIDTH: usize = 40;
+
+/// One traced operation, as reported by the test binary
+#[derive(Debug, Clone)]
+pub struct Bar {
+    pub name: String,
+    pub start: Duration,
+    /// `None` if it never finished
+    pub end: Option<Duration>,
+}
+
+/// What one variant did
+#[derive(Debug, Clone)]
+pub struct Variant {
+    pub label: String,
+    /// `false` if it panicked or hung before reporting
+    pub finished: bool,
+    pub bars: Vec<Bar>,
+}
+
+impl Variant {
+    /// When the last operation ended
+    pub fn total(&self) -> Duration {
+        self.bars.iter().map(|bar| bar.end.unwrap_or(bar.start)).max().unwrap_or_default()
+    }
+}
+
+/// Why there's nothing to chart
+#[derive(Debug)]
+pub enum Failure {
+    /// The learner's code (or a variant) doesn't compile
+    Build,
+    /// Compiling took longer than the build limit
+    BuildTimeout(Duration),
+    /// The check workspace couldn't be written
+    Setup(io::Error),
+}
+
+/// Build the learner's file with the timeline variants and run them all
+pub fn record(exercise: &Exercise) -> Result<Vec<Variant>, Failure> {
+    let bin = workspace::prepare_timeline(exercise).map_err(Failure::Setup)?;
+
+    let config = config::load_config();
+    let build_limit = config.build_timeout();
+    let test_limit = exercise.test_timeout_secs.map(Duration::from_secs).unwrap_or_else(|| config.test_timeout());
+
+    // Passing tests only show their output with --show-output
+    let run = runner::run_tests(&bin, &["__timeline::", "--show-output"], build_limit, test_limit, |_| {});
+    match run.timeout {
+        Some(Timeout::Build) => return Err(Failure::BuildTimeout(build_limit)),
+        Some(Timeout::Test(_)) => {}
+        None if run.tests.is_empty() && !run.status.success() => return Err(Failure::Build),
+        None => {}
+    }
+
+    Ok(parse(exercise, &run.stdout))
+}
+
+/// Pick the reported spans out of the test output
+fn parse(exercise: &Exercise, stdout: &str) -> Vec<Variant> {
+    let mut variants: Vec<Variant> = exercise
+        .timeline
+        .iter()
+        .map(|variant| Variant { label: variant.label.clone(), finished: false, bars: vec![] })
+        .collect();
+
+    let micros = |field: &str| field.parse().ok().map(Duration::from_micros);
+
+    for line in stdout.lines() {
+        let Some(report) = line.strip_prefix("__timeline\t") else {
+            continue;
+        };
+        let fields: Vec<&str> = report.split('\t').collect();
+        let Some(variant) = variants.iter_mut().find(|variant| variant.label == fields[0]) else {
+            continue;
+        };
+
+        match fields[..] {
+            [_] => variant.finished = true,
+            [_, name, start, end] => {
+                if let Some(start) = micros(start) {
+                    variant.bars.push(Bar { name: name.to_string(), start, end: micros(end) });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    variants
+}
+
+/// Draw every variant on one shared time scale
+pub fn print_chart(variants: &[Variant]) {
+    let scale = variants.iter().map(Variant::total).max().unwrap_or_default();
+    let name_width = variants
+        .iter()
+        .flat_map(|variant| variant.bars.iter().map(|bar| bar.name.chars().count()))
+        .max()
+        .unwrap_or(0);
+
+    // Column an instant falls in, with everything ending at the scale
+    // landing on the last column
+    let column = |at: Duration| {
+        if scale.is_zero() {
+            return 0;
+        }
+        ((at.as_secs_f64() / scale.as_secs_f64()) * CHART_WIDTH as f64).round() as usize
+    };
+
+    for variant in variants {
+        let total = format!("{:.1}s", variant.total().as_secs_f64());
+        println!("{}{}",
+            format!("{:<width$}", variant.label, width = name_width + CHART_WIDTH + 5).bright_white().bold(),
+            total.bright_white());
+
+        if !variant.finished {
+            println!("   {}", "💥 This variant didn't finish - run check to see why.".bright_red());
+        } else if variant.bars.is_empty() {
+            println!("   {}", "Nothing was traced - are the functions async and awaited yet?".bright_black());
+        }
+
+        for bar in &variant.bars {
+            let from = column(bar.start).min(CHART_WIDTH - 1);
+            let to = column(bar.end.unwrap_or(scale)).clamp(from + 1, CHART_WIDTH);
+
+            let drawn = "█".repeat(to - from);
+            let drawn = match bar.end {
+                Some(_) => drawn.bright_cyan(),
+                None => drawn.bright_red(),
+            };
+            let when = match bar.end {
+                Some(end) => format!("{:.1}s → {:.1}s", bar.start.as_secs_f64(), end.as_secs_f64()),
+                None => format!("{:.1}s → never finished", bar.start.as_secs_f64()),
+            };
+
+            println!("  {:<width$} │{}{}{}│ {}",
+                bar.name, " ".repeat(from), drawn, " ".repeat(CHART_WIDTH - to), when.bright_black(),
+                width = name_width);
+        }
+        println!();
+    }
+
+    // The axis, labelled at both ends
+    let end_label = format!("{:.1}s", scale.as_secs_f64());
+    println!("  {:<width$} └{}┘", "", "─".repeat(CHART_WIDTH), width = name_width);
+    println!("  {:<width$}  {}{:>rest$}", "", "0s".bright_black(), end_label.bright_black(),
+        width = name_width, rest = CHART_WIDTH - 2);
+}
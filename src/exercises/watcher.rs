@@ -0,0 +1,68 @@
+//! Waiting for the learner to save an exercise file
+//!
+//! The watch is on the file's directory rather than the file itself: many
+//! editors save by writing a new file and renaming it over the old one,
+//! which would silently end a watch on the original. Events for any other
+//! file in the directory are ignored.
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// How long the file has to stay untouched before a save counts - editors
+/// often write a file in several steps
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches a single file for saves
+pub struct FileWatcher {
+    /// Kept alive for as long as events are wanted
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    file: PathBuf,
+}
+
+impl FileWatcher {
+    pub fn new(file: &Path) -> notify::Result<Self> {
+        // Events name paths the way the watched directory was given, so
+        // settle on the canonical form for both
+        let dir = file.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let dir = fs::canonicalize(dir)?;
+        let file = dir.join(file.file_name().unwrap_or_default());
+
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        Ok(FileWatcher { _watcher: watcher, events, file })
+    }
+
+    /// Whether an event means the file was written
+    fn is_save(&self, event: &Event) -> bool {
+        matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            && event.paths.contains(&self.file)
+    }
+
+    /// Block until the file is saved and then left alone for a moment
+    ///
+    /// Returns `false` if the watch stopped working.
+    pub fn wait_for_save(&self) -> bool {
+        loop {
+            match self.events.recv() {
+                Ok(Ok(event)) if self.is_save(&event) => break,
+                Ok(_) => continue,
+                Err(_) => return false,
+            }
+        }
+
+        // Let the rest of a burst of writes go by
+        loop {
+            match self.events.recv_timeout(DEBOUNCE) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Timeout) => return true,
+                Err(RecvTimeoutError::Disconnected) => return false,
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! The check workspace
+//!
+//! Every exercise is checked inside one long-lived crate in
+//! `target/check_workspace`. Each exercise is a binary of its own under
+//! `src/bin/<name>/`, and they all share the lockfile and target directory,
+//! so tokio and futures are compiled once and every later `check` only
+//! rebuilds the learner's file.
+//!
+//! The dependencies are pinned to the exact versions in the tutorial's own
+//! `Cargo.lock`, which also seeds the workspace's lockfile. Whatever built
+//! the tutorial is then enough to check exercises - no registry access
+//! needed, so checks work with `--offline` (or from a vendored set, see
+//! [`crate::config::Config::vendor_dir`]).
+//!
+//! The tutorial's `exercise_support` crate comes along as a path
+//! dependency, so every test suite can use its assertions.
+//!
+//! ```text
+//! target/check_workspace/
+//! ├── Cargo.toml
+//! ├── Cargo.lock
+//! ├── src/bin/ch01_ex01/
+//! │   ├── main.rs          # the learner's file
+//! │   └── hidden_tests.rs  # the exercise's test suite
+//! └── target/
+//! ```
+
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::{instrument, Exercise};
+use crate::config;
+
+pub const WORKSPACE_DIR: &str = "target/check_workspace";
+
+/// Assertions shared by every test suite
+const SUPPORT_CRATE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/exercise_support");
+
+/// The tutorial's own lockfile, exists once it has been built
+const TUTORIAL_LOCKFILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.lock");
+
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+#[derive(Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+}
+
+/// Version of `name` the tutorial was built with, as a cargo requirement
+///
+/// Falls back to `fallback` when there's no lockfile to go by.
+fn locked_requirement(lockfile: Option<&Lockfile>, name: &str, fallback: &str) -> String {
+    lockfile
+        .and_then(|lockfile| {
+            lockfile.package.iter()
+                .filter(|package| package.name == name)
+                .map(|package| package.version.as_str())
+                .max_by_key(|version| {
+                    version.split('.').map(|part| part.parse::<u64>().unwrap_or(0)).collect::<Vec<_>>()
+                })
+        })
+        .map_or_else(|| fallback.to_string(), |version| format!("={}", version))
+}
+
+fn cargo_toml() -> String {
+    let lockfile: Option<Lockfile> = fs::read_to_string(TUTORIAL_LOCKFILE)
+        .ok()
+        .and_then(|content| toml::from_str(&content).ok());
+
+    format!(r#"# Generated by async-book-exercises - every checked exercise is a binary
+# under src/bin/, all sharing one lockfile and target directory.
+[package]
+name = "exercise_checks"
+version = "0.1.0"
+edition = "2021"
+publish = false
+
+# Pinned to the versions the tutorial itself was built with
+[dependencies]
+tokio = {{ version = "{}", features = ["full", "test-util"] }}
+futures = "{}"
+exercise_support = {{ path = {:?} }}
+
+# Keep this crate out of any workspace it happens to sit in
+[workspace]
+"#,
+        locked_requirement(lockfile.as_ref(), "tokio", "1.0"),
+        locked_requirement(lockfile.as_ref(), "futures", "0.3"),
+        SUPPORT_CRATE)
+}
+
+/// Path to pass to `cargo --manifest-path`
+pub fn manifest_path() -> String {
+    format!("{}/Cargo.toml", WORKSPACE_DIR)
+}
+
+/// A `cargo <subcommand>` for one exercise binary in the workspace
+///
+/// Honors the `offline` and `vendor_dir` settings. `--offline` on the
+/// command line reaches cargo through `CARGO_NET_OFFLINE`.
+pub fn cargo_command(subcommand: &str, bin: &str) -> Command {
+    let config = config::load_config();
+
+    let mut command = Command::new("cargo");
+    command.args([subcommand, "--manifest-path", &manifest_path(), "--bin", bin]);
+
+    if config.offline {
+        command.arg("--offline");
+    }
+
+    if let Some(vendor_dir) = &config.vendor_dir {
+        let vendor_dir = fs::canonicalize(vendor_dir).unwrap_or_else(|_| vendor_dir.clone());
+        command.args([
+            "--config", "source.crates-io.replace-with=\"vendored-sources\"",
+            "--config", &format!("source.vendored-sources.directory={:?}", vendor_dir.display().to_string()),
+        ]);
+    }
+
+    command
+}
+
+/// Name of the binary target an exercise is checked as
+pub fn bin_name(exercise: &Exercise) -> String {
+    exercise.relative_stem().replace('/', "_")
+}
+
+/// The learner's code as the compiler names it in diagnostics
+pub fn source_file(bin: &str) -> String {
+    format!("src/bin/{}/main.rs", bin)
+}
+
+fn bin_dir(exercise: &Exercise) -> PathBuf {
+    Path::new(WORKSPACE_DIR).join("src/bin").join(bin_name(exercise))
+}
+
+/// Write a file only when its contents change, so cargo doesn't see a
+/// fresh mtime and rebuild for nothing
+///
+/// Returns whether anything was written.
+fn write_if_changed(path: &Path, contents: &str) -> io::Result<bool> {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+        return Ok(false);
+    }
+    fs::write(path, contents)?;
+    Ok(true)
+}
+
+/// An exercise's binary, ready to build
+pub struct Prepared {
+    /// The binary name to build and test
+    pub bin: String,
+    /// The learner's file as it was read into the binary
+    pub code: String,
+}
+
+/// Bring the exercise's binary up to date with the learner's file
+pub fn prepare(exercise: &Exercise) -> io::Result<Prepared> {
+    prepare_with(exercise, "")
+}
+
+/// Like [`prepare`], plus a `__timeline` test module that runs each of the
+/// exercise's timeline variants under a trace and reports its spans
+pub fn prepare_timeline(exercise: &Exercise) -> io::Result<String> {
+    let mut module = String::from("\n#[cfg(test)]\nmod __timeline {\n    use super::*;\n");
+    for (index, variant) in exercise.timeline.iter().enumerate() {
+        module.push_str(&format!(
+            "\n    #[tokio::test(start_paused = true)]\n    async fn variant_{}() {{\n        \
+             let (_, trace) = ::exercise_support::traced({}).await;\n        \
+             trace.report({:?});\n    }}\n",
+            index, variant.run, variant.label
+        ));
+    }
+    module.push_str("}\n");
+
+    prepare_with(exercise, &module).map(|prepared| prepared.bin)
+}
+
+/// Write the exercise's binary, with `extra` appended to its source
+fn prepare_with(exercise: &Exercise, extra: &str) -> io::Result<Prepared> {
+    let dir = bin_dir(exercise);
+    fs::create_dir_all(&dir)?;
+
+    // A new manifest means new pins, so start over from the tutorial's
+    // lockfile - cargo prunes whatever the workspace doesn't use
+    let workspace = Path::new(WORKSPACE_DIR);
+    let manifest_changed = write_if_changed(&workspace.join("Cargo.toml"), &cargo_toml())?;
+    if manifest_changed || !workspace.join("Cargo.lock").exists() {
+        fs::copy(TUTORIAL_LOCKFILE, workspace.join("Cargo.lock")).ok();
+    }
+
+    // Traced functions get wrapped in spans, and the hidden tests go next
+    // to the learner's code as a module of their own. Neither adds lines
+    // before the learner's code, so the line numbers in compiler errors
+    // still point at the learner's file.
+    let code = fs::read_to_string(exercise.filename())?;
+    let mut source = instrument::instrument(&code, &exercise.trace);
+    let hidden_tests = dir.join("hidden_tests.rs");
+    match &exercise.tests {
+        Some(tests) => {
+            write_if_changed(&hidden_tests, &fs::read_to_string(tests)?)?;
+            source.push_str("\n#[cfg(test)]\n#[path = \"hidden_tests.rs\"]\nmod __hidden_tests;\n");
+        }
+        None => {
+            fs::remove_file(&hidden_tests).ok();
+        }
+    }
+    source.push_str(extra);
+    write_if_changed(&dir.join("main.rs"), &source)?;
+
+    Ok(Prepared { bin: bin_name(exercise), code })
+}
+
+/// Drop every exercise's sources but keep the compiled dependencies around
+pub fn clean_sources() {
+    fs::remove_dir_all(Path::new(WORKSPACE_DIR).join("src")).ok();
+}
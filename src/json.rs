@@ -0,0 +1,145 @@
+//! Machine-readable output for `--format json`
+//!
+//! Scripts get the facts the screens show - exercise metadata, statuses,
+//! per-test results, file paths - as a single JSON document per command,
+//! without colors or screen clearing. Exercises look the same everywhere
+//! they appear, see [`exercise`].
+
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::exercises::{self, CheckReport, Exercise, ExerciseState, Generated};
+use crate::progress::Progress;
+
+/// An exercise and where the learner stands with it
+pub fn exercise(exercise: &Exercise, progress: &Progress) -> Value {
+    let filename = exercise.filename();
+    let status = match exercise.state(&progress.completed_exercises) {
+        ExerciseState::Completed => "completed",
+        _ if progress.current_exercise.as_ref() == Some(&exercise.id) => "current",
+        ExerciseState::Unlocked => "unlocked",
+        ExerciseState::Locked => "locked",
+    };
+    let (tests_passed, tests_total) = match progress.tests_passed(&exercise.id) {
+        Some((passed, total)) => (Some(passed), Some(total)),
+        None => (None, None),
+    };
+
+    json!({
+        "id": exercise.id,
+        "title": exercise.title,
+        "pack": exercise.pack,
+        "chapter": exercise.chapter,
+        "chapter_title": exercise.chapter_title,
+        "number": exercise.number,
+        "requires": exercise.requires,
+        "status": status,
+        "file": filename,
+        "file_exists": Path::new(&filename).exists(),
+        "tests_passed": tests_passed,
+        "tests_total": tests_total,
+        "hints": exercise.hints.len(),
+        "hints_used": progress.hints_used.get(&exercise.id).copied().unwrap_or(0),
+    })
+}
+
+/// The exercise with this id, if there is one
+fn find(id: &str, progress: &Progress) -> Option<Value> {
+    exercises::find_exercise(id).map(|e| exercise(e, progress))
+}
+
+/// For `list`: every exercise
+pub fn list(progress: &Progress) -> Value {
+    let exercises: Vec<Value> = exercises::get_all_exercises().iter().map(|e| exercise(e, progress)).collect();
+    json!({ "exercises": exercises })
+}
+
+/// For `progress`: the totals, the current exercise and every test result
+pub fn progress(progress: &Progress) -> Value {
+    let tally = progress.tally();
+    json!({
+        "completed": tally.completed,
+        "total": tally.total,
+        "credit": tally.credit,
+        "percentage": (tally.credit / tally.total.max(1) as f32 * 100.0) as u32,
+        "completed_exercises": progress.completed_exercises,
+        "current_exercise": progress.current_exercise.as_deref().and_then(|id| find(id, progress)),
+        "test_results": progress.test_results,
+        "hints_used": progress.hints_used,
+    })
+}
+
+/// For `check`: how it went, test by test, and what comes next
+pub fn check(id: &str, report: &CheckReport, progress: &Progress) -> Value {
+    let checked = exercises::find_exercise(id);
+    let (passed, total) = exercises::results::tally(&report.tests,
+        checked.is_some_and(|exercise| exercise.tests.is_some()));
+
+    // Compiler errors point at the learner's file, not the check workspace
+    let source = checked.map(|e| exercises::workspace::source_file(&exercises::workspace::bin_name(e)));
+    let file = checked.map(Exercise::filename);
+    let errors: Vec<Value> = report.errors.iter()
+        .map(|error| json!({
+            "code": error.code.as_ref().map(|code| &code.code),
+            "message": error.message,
+            "line": source.as_deref().and_then(|source| error.line_in(source)),
+            "rendered": match (&error.rendered, &source, &file) {
+                (Some(rendered), Some(source), Some(file)) => Some(rendered.replace(source.as_str(), file)),
+                (rendered, _, _) => rendered.clone(),
+            },
+        }))
+        .collect();
+    let findings: Vec<Value> = report.findings.iter()
+        .map(|finding| {
+            let (problem, fix) = finding.describe();
+            json!({ "line": finding.line, "problem": problem, "fix": fix })
+        })
+        .collect();
+
+    json!({
+        "exercise": checked.map(|e| exercise(e, progress)),
+        "outcome": report.outcome,
+        "passed": report.outcome.passed(),
+        "tests_passed": passed,
+        "tests_total": total,
+        "tests": report.tests,
+        "errors": errors,
+        "lint": findings,
+        "next_exercise": progress.current_exercise.as_deref()
+            .filter(|_| report.outcome.passed())
+            .and_then(|next| find(next, progress)),
+    })
+}
+
+/// For `next` and `run`: the exercise and what happened to its file
+pub fn generated(id: &str, generated: &Generated, progress: &Progress) -> Value {
+    let (file, backup) = match generated {
+        Generated::Written => ("written", None),
+        Generated::KeptExisting => ("kept_existing", None),
+        Generated::Regenerated(backup) => ("regenerated", Some(&backup.name)),
+    };
+    json!({
+        "exercise": find(id, progress),
+        "generated": file,
+        "backup": backup,
+    })
+}
+
+/// For `next` once there's nothing left
+pub fn all_completed(progress: &Progress) -> Value {
+    json!({
+        "exercise": null,
+        "completed_all": true,
+        "completed_exercises": progress.completed_exercises,
+    })
+}
+
+/// For commands that can't do what was asked: `reason` is
+/// `invalid_exercise`, `locked` or `no_current_exercise`
+pub fn error(reason: &str, id: Option<&str>, missing: &[String]) -> Value {
+    json!({
+        "error": reason,
+        "id": id,
+        "missing_prerequisites": missing,
+    })
+}
@@ -1 +1,3 @@
-pub mod exercises;
\ No newline at end of file
+pub mod config;
+pub mod exercises;
+pub mod render;
\ No newline at end of file
@@ -11,9 +11,15 @@
 
 use clap::{Parser, Subcommand};
 use colored::*;
+use std::process::ExitCode;
 
+mod config;
 mod exercises;
+mod json;
 mod progress;
+mod render;
+
+use progress::Exit;
 
 #[derive(Parser)]
 #[command(name = "async-book-exercises")]
@@ -21,6 +27,18 @@ mod progress;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    /// Check exercises without touching the network
+    #[arg(long, global = true)]
+    offline: bool,
+    /// How screens look: colorful, plain or compact (overrides the config)
+    #[arg(long, global = true, value_enum)]
+    theme: Option<render::Theme>,
+    /// Print screens (text) or a JSON document for scripts (json)
+    #[arg(long, global = true, value_enum, default_value_t)]
+    format: render::Format,
+    /// Never clear the screen (it's only cleared on a terminal anyway)
+    #[arg(long, global = true)]
+    no_clear: bool,
 }
 
 #[derive(Subcommand)]
@@ -30,67 +48,76 @@ enum Commands {
     /// Show current progress
     Progress,
     /// Start or continue the next exercise
-    Next,
+    Next {
+        /// Regenerate the exercise file even if you've changed it (a backup is kept)
+        #[arg(long)]
+        force: bool,
+    },
     /// Run a specific exercise
     Run {
         /// Exercise ID (e.g., "1.1" or "2.3")
         id: String,
+        /// Regenerate the exercise file even if you've changed it (a backup is kept)
+        #[arg(long)]
+        force: bool,
+    },
+    /// List the backups of an exercise, or restore one of them
+    Restore {
+        /// Exercise ID (e.g., "1.1" or "2.3")
+        id: String,
+        /// Backup to restore (as shown in the list)
+        backup: Option<String>,
     },
     /// Check if current exercise passes all tests
     Check,
+    /// Check the current exercise every time you save it, moving on when it passes
+    Watch,
+    /// Review, compare and restore past attempts at an exercise
+    History {
+        /// Exercise ID (e.g., "1.1" or "2.3")
+        id: String,
+        /// Show what changed in attempt <n> (or between attempts <n> and <m>)
+        #[arg(long, num_args = 1..=2, value_names = ["N", "M"], conflicts_with = "restore")]
+        diff: Option<Vec<u32>>,
+        /// Put attempt <n> back into the exercise file (the current one is backed up)
+        #[arg(long, value_name = "N")]
+        restore: Option<u32>,
+    },
+    /// Reveal the next hint for the current exercise (or <id>)
+    Hint {
+        /// Exercise ID (e.g., "1.1" or "2.3")
+        id: Option<String>,
+    },
+    /// Show the reference solution next to yours
+    Solution {
+        /// Exercise ID (e.g., "1.1" or "2.3")
+        id: String,
+        /// Show it even though the exercise isn't completed yet
+        #[arg(long)]
+        reveal: bool,
+    },
+    /// Chart when each async operation ran, sequential vs concurrent
+    Timeline {
+        /// Exercise ID (e.g., "1.1" or "1.2")
+        id: String,
+    },
     /// Reset progress and start over
     Reset,
 }
 
 fn welcome_screen() {
-    // Clear screen for a fresh start
-    print!("\x1B[2J\x1B[1;1H");
-    
-    // Title
-    println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-    println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-    println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
-    println!();
+    render::screen();
     
     // Load progress data
     let progress = progress::load_progress();
-    let total = exercises::get_total_exercises();
-    let completed = progress.completed_exercises.len();
-    let percentage = (completed as f32 / total as f32 * 100.0) as u32;
     
     // Progress Section
     println!("{}", "📊 Your Progress".bright_yellow().bold());
-    print!("   ");
-    let bar_width = 40;
-    let filled = (bar_width * completed / total).max(0);
-    print!("[");
-    for i in 0..bar_width {
-        if i < filled {
-            print!("{}", "█".bright_green());
-        } else {
-            print!("{}", "░".bright_black());
-        }
-    }
-    println!("] {}/{} ({}%)", completed, total, percentage);
-    
-    // Current status line
-    if let Some(current) = &progress.current_exercise {
-        if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == *current) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-            println!("   {} Exercise {}: {}", "Working on".bright_cyan(), current.bright_white(), exercise.title);
-            if std::path::Path::new(&filename).exists() {
-                println!("   {} {}", "Edit".bright_blue(), filename.bright_white().underline());
-            }
-        }
-    } else if completed == 0 {
-        println!("   {}", "Ready to start your async journey!".bright_green());
-    } else if completed == total {
-        println!("   {} All exercises completed!", "🎉".bright_green());
-    }
+    render::progress_bar(progress.tally());
+    progress::print_status(&progress);
     
     println!();
-    println!("{}", "─────────────────────────────────────────────────".bright_black());
-    println!();
+    render::separator();
     
     // Quick command reference
     println!("   {}", "Commands:".bright_yellow());
@@ -104,54 +131,72 @@ fn welcome_screen() {
     
     // Action prompt based on state
     println!();
-    if let Some(current) = &progress.current_exercise {
-        if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == *current) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-            if std::path::Path::new(&filename).exists() {
-                println!("   {} to test your solution", "cargo run -- check".bright_green().bold());
-            } else {
-                println!("   {} to generate the exercise", "cargo run -- next".bright_green().bold());
-            }
-        }
-    } else if completed == 0 {
-        println!("   {} to begin!", "cargo run -- next".bright_green().bold());
-    } else {
-        println!("   {} for more exercises", "cargo run -- next".bright_green().bold());
-    }
-    
-    println!();
+    progress::print_next_action(&progress);
 }
 
-fn main() {
+fn main() -> ExitCode {
     let cli = Cli::parse();
+    render::init(cli.theme, cli.format, cli.no_clear);
+
+    // Every cargo we spawn picks this up
+    if cli.offline {
+        std::env::set_var("CARGO_NET_OFFLINE", "true");
+    }
 
-    match &cli.command {
+    let exit = match &cli.command {
         Some(Commands::List) => {
             let progress = progress::load_progress();
-            exercises::list_exercises(&progress.completed_exercises, &progress.current_exercise);
+            if render::json() {
+                render::print_json(&json::list(&progress));
+            } else {
+                exercises::list_exercises(&progress.completed_exercises, &progress.current_exercise,
+                    &progress.tests_passed_by_exercise());
+            }
+            Exit::Success
         }
         Some(Commands::Progress) => {
             let progress = progress::load_progress();
             progress::show_progress(&progress);
+            Exit::Success
         }
-        Some(Commands::Next) => {
+        Some(Commands::Next { force }) => {
             let mut progress = progress::load_progress();
-            progress::start_next_exercise(&mut progress);
+            progress::start_next_exercise(&mut progress, *force);
+            Exit::Success
         }
-        Some(Commands::Run { id }) => {
+        Some(Commands::Run { id, force }) => {
             let mut progress = progress::load_progress();
-            progress::run_exercise(id, &mut progress);
+            progress::run_exercise(id, &mut progress, *force)
         }
+        Some(Commands::Restore { id, backup }) => progress::restore_exercise(id, backup.as_deref()),
         Some(Commands::Check) => {
             let mut progress = progress::load_progress();
-            progress::check_current_exercise(&mut progress);
+            progress::check_current_exercise(&mut progress)
+        }
+        Some(Commands::Watch) => {
+            let mut progress = progress::load_progress();
+            progress::watch_exercises(&mut progress)
+        }
+        Some(Commands::History { id, diff, restore }) => progress::show_history(id, diff.as_deref(), *restore),
+        Some(Commands::Hint { id }) => {
+            let mut progress = progress::load_progress();
+            progress::show_hint(id.as_deref(), &mut progress)
+        }
+        Some(Commands::Solution { id, reveal }) => {
+            let progress = progress::load_progress();
+            progress::show_solution(id, &progress, *reveal)
         }
+        Some(Commands::Timeline { id }) => progress::show_timeline(id),
         Some(Commands::Reset) => {
             progress::reset_progress();
+            Exit::Success
         }
         None => {
             // No command specified, show welcome screen
             welcome_screen();
+            Exit::Success
         }
-    }
+    };
+
+    ExitCode::from(exit.code())
 }
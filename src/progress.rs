@@ -1,9 +1,14 @@
 
 
 use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
+use std::io;
 use std::path::Path;
 use crate::exercises;
+use crate::json;
+use crate::render;
+use crate::exercises::results::{TestResult, TestStatus};
 use colored::*;
 
 const PROGRESS_FILE: &str = ".async-book-progress.json";
@@ -13,6 +18,15 @@ const PROGRESS_FILE: &str = ".async-book-progress.json";
 pub struct Progress {
     pub completed_exercises: Vec<String>,
     pub current_exercise: Option<String>,
+    /// Per-test results of the latest check of each exercise
+    #[serde(default)]
+    pub test_results: BTreeMap<String, Vec<TestResult>>,
+    /// Every test that has passed in any check, per exercise
+    #[serde(default)]
+    pub passed_tests: BTreeMap<String, BTreeSet<String>>,
+    /// How many hints have been revealed, per exercise
+    #[serde(default)]
+    pub hints_used: BTreeMap<String, usize>,
 }
 
 impl Default for Progress {
@@ -20,6 +34,68 @@ impl Default for Progress {
         Progress {
             completed_exercises: vec![],
             current_exercise: Some("1.1".to_string()),
+            test_results: BTreeMap::new(),
+            passed_tests: BTreeMap::new(),
+            hints_used: BTreeMap::new(),
+        }
+    }
+}
+
+impl Progress {
+    /// "2/3 tests passing" for the latest check of an exercise, if it got that far
+    pub fn test_summary(&self, id: &str) -> Option<String> {
+        let tests = self.test_results.get(id)?;
+        let (passed, total) = exercises::results::tally(tests, has_hidden_suite(id));
+        Some(format!("{}/{} tests passing", passed, total))
+    }
+
+    /// Tests of an exercise that have passed in some check, out of the
+    /// tests its latest check ran
+    ///
+    /// A test fixed in one attempt and broken again in the next still
+    /// counts - that's the partial credit.
+    pub fn tests_passed(&self, id: &str) -> Option<(usize, usize)> {
+        let tests = self.test_results.get(id)?;
+        let ever_passed = self.passed_tests.get(id);
+
+        let hidden_suite = has_hidden_suite(id);
+        let counted: Vec<&TestResult> = tests.iter()
+            .filter(|t| exercises::results::counts(t, hidden_suite))
+            .collect();
+        let passed = counted.iter()
+            .filter(|t| ever_passed.is_some_and(|names| names.contains(&t.name)))
+            .count();
+
+        Some((passed, counted.len()))
+    }
+
+    /// `tests_passed` for every exercise that has been checked
+    pub fn tests_passed_by_exercise(&self) -> BTreeMap<String, (usize, usize)> {
+        self.test_results
+            .keys()
+            .filter_map(|id| Some((id.clone(), self.tests_passed(id)?)))
+            .collect()
+    }
+
+    /// Exercises completed out of all there are, for the progress bar
+    pub fn tally(&self) -> render::Tally {
+        let all_exercises = exercises::get_all_exercises();
+        render::Tally {
+            completed: all_exercises.iter().filter(|e| self.completed_exercises.contains(&e.id)).count(),
+            credit: all_exercises.iter().map(|e| self.credit(&e.id)).sum(),
+            total: all_exercises.len(),
+        }
+    }
+
+    /// How much of an exercise is done, from 0.0 to 1.0
+    pub fn credit(&self, id: &str) -> f32 {
+        if self.completed_exercises.iter().any(|done| done == id) {
+            return 1.0;
+        }
+
+        match self.tests_passed(id) {
+            Some((passed, total)) if total > 0 => passed as f32 / total as f32,
+            _ => 0.0,
         }
     }
 }
@@ -37,255 +113,840 @@ pub fn save_progress(progress: &Progress) {
     fs::write(PROGRESS_FILE, json).unwrap();
 }
 
-pub fn show_progress(progress: &Progress) {
-    // Clear screen for consistent presentation
-    print!("\x1B[2J\x1B[1;1H");
-    
-    // Title
-    println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-    println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-    println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
-    println!();
-    
-    let total_exercises = exercises::get_total_exercises();
-    let completed = progress.completed_exercises.len();
-    let percentage = (completed as f32 / total_exercises as f32 * 100.0) as u32;
-    
-    // Progress Section
-    println!("{}", "📊 Your Progress".bright_yellow().bold());
-    print!("   ");
-    let bar_width = 40;
-    let filled = (bar_width * completed / total_exercises).max(0);
-    print!("[");
-    for i in 0..bar_width {
-        if i < filled {
-            print!("{}", "█".bright_green());
-        } else {
-            print!("{}", "░".bright_black());
+/// How a command went, as the exit code scripts and CI jobs see
+///
+/// 2 is left out: clap exits with it when the arguments are wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// Did what was asked (0)
+    Success,
+    /// The tests failed, or passed with blocking code the exercise denies (1)
+    TestsFailed,
+    /// The exercise doesn't compile, or compiling it took too long (3)
+    BuildFailed,
+    /// A test hung and had to be stopped (4)
+    Hung,
+    /// No such exercise, exercise file, backup, attempt or hint (5)
+    NotFound,
+    /// The exercise or its solution isn't unlocked yet (6)
+    Locked,
+    /// The check workspace (or `watch`'s file watcher) couldn't be set up (7)
+    SetupFailed,
+    /// The exercise file, or the backup of it, couldn't be written (8)
+    WriteFailed,
+}
+
+impl Exit {
+    pub fn code(self) -> u8 {
+        match self {
+            Exit::Success => 0,
+            Exit::TestsFailed => 1,
+            Exit::BuildFailed => 3,
+            Exit::Hung => 4,
+            Exit::NotFound => 5,
+            Exit::Locked => 6,
+            Exit::SetupFailed => 7,
+            Exit::WriteFailed => 8,
         }
     }
-    println!("] {}/{} ({}%)", completed, total_exercises, percentage);
-    println!();
-    
-    // Current exercise
+}
+
+impl From<exercises::CheckOutcome> for Exit {
+    fn from(outcome: exercises::CheckOutcome) -> Self {
+        match outcome {
+            exercises::CheckOutcome::Passed => Exit::Success,
+            exercises::CheckOutcome::TestsFailed => Exit::TestsFailed,
+            exercises::CheckOutcome::BuildFailed => Exit::BuildFailed,
+            exercises::CheckOutcome::Hung => Exit::Hung,
+            exercises::CheckOutcome::Missing => Exit::NotFound,
+            exercises::CheckOutcome::SetupFailed => Exit::SetupFailed,
+        }
+    }
+}
+
+/// Where the learner is: the exercise they're on and how it's going
+pub fn print_status(progress: &Progress) {
+    let total = exercises::get_total_exercises();
+    let completed = progress.completed_exercises.len();
+
     if let Some(current) = &progress.current_exercise {
-        if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == *current) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
+        if let Some(exercise) = exercises::find_exercise(current) {
+            let filename = exercise.filename();
             println!("   {} Exercise {}: {}", "Working on".bright_cyan(), current.bright_white(), exercise.title);
-            if std::path::Path::new(&filename).exists() {
+            if let Some(summary) = progress.test_summary(current) {
+                println!("   {} {}", "Last check".bright_blue(), summary);
+            }
+            if let Some(used) = progress.hints_used.get(current) {
+                println!("   {} {} of {}", "Hints used".bright_blue(), used, exercise.hints.len());
+            }
+            if Path::new(&filename).exists() {
                 println!("   {} {}", "Edit".bright_blue(), filename.bright_white().underline());
             }
         }
     } else if completed == 0 {
         println!("   {}", "Ready to start your async journey!".bright_green());
-    } else if completed == total_exercises {
+    } else if completed == total {
         println!("   {} All exercises completed!", "🎉".bright_green());
     }
+}
+
+/// The command to run next, going by where the learner is
+pub fn print_next_action(progress: &Progress) {
+    if let Some(current) = &progress.current_exercise {
+        if let Some(exercise) = exercises::find_exercise(current) {
+            if Path::new(&exercise.filename()).exists() {
+                render::next_action("cargo run -- check", "to test your solution");
+            } else {
+                render::next_action("cargo run -- next", "to generate the exercise");
+            }
+        }
+    } else if progress.completed_exercises.is_empty() {
+        render::next_action("cargo run -- next", "to begin!");
+    }
+}
+
+pub fn show_progress(progress: &Progress) {
+    if render::json() {
+        render::print_json(&json::progress(progress));
+        return;
+    }
+
+    render::screen();
+    
+    // Progress Section
+    println!("{}", "📊 Your Progress".bright_yellow().bold());
+    render::progress_bar(progress.tally());
+    println!();
+    print_status(progress);
     
     // Completed exercises
     if !progress.completed_exercises.is_empty() {
         println!();
         println!("{}", "✅ Completed Exercises".bright_yellow().bold());
         for ex_id in &progress.completed_exercises {
-            if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == *ex_id) {
+            if let Some(exercise) = exercises::find_exercise(ex_id) {
                 println!("   {} - {}", ex_id.bright_white(), exercise.title);
             }
         }
     }
     
     println!();
-    println!("{}", "─────────────────────────────────────────────────".bright_black());
-    println!();
+    render::separator();
     
     // Show next action
-    if let Some(current) = &progress.current_exercise {
-        if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == *current) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-            if std::path::Path::new(&filename).exists() {
-                println!("   {} to test your solution", "cargo run -- check".bright_green().bold());
-            } else {
-                println!("   {} to generate the exercise", "cargo run -- next".bright_green().bold());
+    print_next_action(progress);
+}
+
+pub fn start_next_exercise(progress: &mut Progress, force: bool) {
+    let unlocked = exercises::get_unlocked_exercises(&progress.completed_exercises);
+
+    // Keep going with the current exercise if it's still open, otherwise
+    // pick the first one that's unlocked
+    let next = progress.current_exercise.clone()
+        .filter(|current| unlocked.contains(current))
+        .or_else(|| unlocked.first().cloned());
+
+    if let Some(next) = next {
+        progress.current_exercise = Some(next.clone());
+        save_progress(progress);
+
+        render::screen();
+
+        // Generate the exercise, keeping any work already in progress
+        if let Some(generated) = exercises::generate_exercise(&next, force) {
+            if render::json() {
+                render::print_json(&json::generated(&next, &generated, progress));
+                return;
             }
+            print_generated(&next, &generated);
+
+            print_other_unlocked(&unlocked, &next);
         }
-    } else if completed == 0 {
-        println!("   {} to begin!", "cargo run -- next".bright_green().bold());
+    } else if render::json() {
+        render::print_json(&json::all_completed(progress));
+    } else {
+        render::screen();
+        
+        print_congratulations();
     }
-    
+}
+
+/// The end of the road
+fn print_congratulations() {
+    println!("{}", "🎉 CONGRATULATIONS!".bright_green().bold());
+    println!("   You've completed ALL the exercises!");
+    println!();
+    println!("   You've mastered the fundamentals of async Rust programming.");
+    println!("   Time to build something amazing with your new skills! 🚀");
     println!();
 }
 
-pub fn start_next_exercise(progress: &mut Progress) {
-    if let Some(next) = exercises::get_next_exercise(&progress.completed_exercises) {
-        progress.current_exercise = Some(next.clone());
-        save_progress(progress);
+/// Describe what generating an exercise did to the learner's file
+fn print_generated(id: &str, generated: &exercises::Generated) {
+    if let Some(exercise) = exercises::find_exercise(id) {
+        let filename = exercise.filename();
 
-        // Clear screen for consistent presentation
-        print!("\x1B[2J\x1B[1;1H");
-        
-        // Title
-        println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-        println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-        println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
+        match generated {
+            exercises::Generated::Written => {
+                println!("{}", "📝 Exercise Generated".bright_yellow().bold());
+            }
+            exercises::Generated::KeptExisting => {
+                println!("{}", "📝 Continuing Exercise".bright_yellow().bold());
+            }
+            exercises::Generated::Regenerated(_) => {
+                println!("{}", "📝 Exercise Regenerated".bright_yellow().bold());
+            }
+        }
+        println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+        println!("   {} {}", "File".bright_blue(), filename.bright_white().underline());
         println!();
 
-        // Generate the exercise
-        exercises::generate_exercise(&next);
-        
-        if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == next) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-            
-            println!("{}", "📝 Exercise Generated".bright_yellow().bold());
-            println!("   Exercise {}: {}", next.bright_white(), exercise.title);
-            println!("   {} {}", "File".bright_blue(), filename.bright_white().underline());
-            println!();
-            
-            println!("{}", "─────────────────────────────────────────────────".bright_black());
-            println!();
-            
-            println!("   Open the file and replace the {} sections with your code.", "todo!()".bright_red());
-            println!("   The instructions are in the file's documentation block.");
-            println!();
-            println!("   {} when you're ready to test your solution!", "cargo run -- check".bright_green().bold());
-            println!();
+        match generated {
+            exercises::Generated::Written => {}
+            exercises::Generated::KeptExisting => {
+                println!("   Your existing work was kept - nothing was overwritten.");
+                println!("   {} to start over from the template (your version is backed up first)",
+                    format!("cargo run -- run {} --force", id).bright_cyan());
+                println!();
+            }
+            exercises::Generated::Regenerated(backup) => {
+                println!("   Your previous version was saved as backup {}.", backup.name.bright_white());
+                println!("   {} to get it back",
+                    format!("cargo run -- restore {} {}", id, backup.name).bright_cyan());
+                println!();
+            }
         }
-    } else {
-        // Clear screen for consistent presentation
-        print!("\x1B[2J\x1B[1;1H");
         
-        // Title
-        println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-        println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-        println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
-        println!();
+        render::separator();
         
-        println!("{}", "🎉 CONGRATULATIONS!".bright_green().bold());
-        println!("   You've completed ALL the exercises!");
-        println!();
-        println!("   You've mastered the fundamentals of async Rust programming.");
-        println!("   Time to build something amazing with your new skills! 🚀");
+        println!("   Open the file and replace the {} sections with your code.", "todo!()".bright_red());
+        println!("   The instructions are in the file's documentation block.");
         println!();
+        render::next_action("cargo run -- check", "when you're ready to test your solution!");
     }
 }
 
-pub fn run_exercise(id: &str, progress: &mut Progress) {
-    if exercises::is_valid_exercise(id) {
-        progress.current_exercise = Some(id.to_string());
-        save_progress(progress);
+/// Let the learner know what else they could be working on
+fn print_other_unlocked(unlocked: &[String], chosen: &str) {
+    let others: Vec<&String> = unlocked.iter().filter(|id| id.as_str() != chosen).collect();
 
-        // Clear screen for consistent presentation
-        print!("\x1B[2J\x1B[1;1H");
-        
-        // Title
-        println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-        println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-        println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
+    if !others.is_empty() {
+        println!("{}", "🔓 Also Unlocked".bright_yellow().bold());
+        for id in others {
+            if let Some(exercise) = exercises::find_exercise(id) {
+                println!("   {} - {}  {}", id.bright_white(), exercise.title,
+                    format!("cargo run -- run {}", id).bright_black());
+            }
+        }
         println!();
+    }
+}
 
-        // Generate the exercise
-        exercises::generate_exercise(id);
+pub fn run_exercise(id: &str, progress: &mut Progress, force: bool) -> Exit {
+    let missing = exercises::find_exercise(id)
+        .map(|e| e.missing_prerequisites(&progress.completed_exercises))
+        .unwrap_or_default();
+
+    if render::json() && !missing.is_empty() {
+        render::print_json(&json::error("locked", Some(id), &missing));
+        Exit::Locked
+    } else if !missing.is_empty() {
+        render::screen();
         
-        if let Some(exercise) = exercises::get_all_exercises().iter().find(|e| e.id == id) {
-            let filename = format!("exercises/ch{:02}_ex{:02}.rs", exercise.chapter, exercise.number);
-            
-            println!("{}", "📝 Exercise Generated".bright_yellow().bold());
-            println!("   Exercise {}: {}", id.bright_white(), exercise.title);
-            println!("   {} {}", "File".bright_blue(), filename.bright_white().underline());
-            println!();
-            
-            println!("{}", "─────────────────────────────────────────────────".bright_black());
-            println!();
-            
-            println!("   Open the file and replace the {} sections with your code.", "todo!()".bright_red());
-            println!("   The instructions are in the file's documentation block.");
-            println!();
-            println!("   {} when you're ready to test your solution!", "cargo run -- check".bright_green().bold());
-            println!();
+        println!("{}", "🔒 Exercise Locked".bright_red().bold());
+        println!("   Exercise {} needs these completed first:", id.bright_white());
+        for req in &missing {
+            println!("   • {}", req.bright_white());
         }
-    } else {
-        // Clear screen for consistent presentation
-        print!("\x1B[2J\x1B[1;1H");
-        
-        // Title
-        println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-        println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-        println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
         println!();
-        
-        println!("{}", "❌ Invalid Exercise".bright_red().bold());
-        println!("   Exercise {} not found", id.bright_white());
-        println!();
-        println!("   {} to see all available exercises", "cargo run -- list".bright_cyan());
+        println!("   {} to see what's unlocked", "cargo run -- list".bright_cyan());
         println!();
+        Exit::Locked
+    } else if exercises::is_valid_exercise(id) {
+        progress.current_exercise = Some(id.to_string());
+        save_progress(progress);
+
+        render::screen();
+
+        // Generate the exercise, keeping any work already in progress
+        if let Some(generated) = exercises::generate_exercise(id, force) {
+            if render::json() {
+                render::print_json(&json::generated(id, &generated, progress));
+            } else {
+                print_generated(id, &generated);
+            }
+        }
+        Exit::Success
+    } else if render::json() {
+        render::print_json(&json::error("invalid_exercise", Some(id), &[]));
+        Exit::NotFound
+    } else {
+        render::screen();
+        render::invalid_exercise(id);
+        Exit::NotFound
     }
 }
 
-pub fn check_current_exercise(progress: &mut Progress) {
+/// Whether only `id`'s hidden test suite counts towards passing it, see
+/// [`exercises::results::counts`]
+fn has_hidden_suite(id: &str) -> bool {
+    exercises::find_exercise(id).is_some_and(|exercise| exercise.tests.is_some())
+}
+
+/// Save what a check of `id` found: the attempt, the test results, and -
+/// if it passed - that it's completed, moving on to the next exercise
+fn record_check(progress: &mut Progress, id: &str, report: &exercises::CheckReport) {
+    record_attempt(id, report);
+
+    if !report.tests.is_empty() {
+        let hidden_suite = has_hidden_suite(id);
+        let passed = progress.passed_tests.entry(id.to_string()).or_default();
+        let newly_passed = report.tests.iter()
+            .filter(|t| t.status == TestStatus::Passed && exercises::results::counts(t, hidden_suite));
+        for test in newly_passed {
+            passed.insert(test.name.clone());
+        }
+        progress.test_results.insert(id.to_string(), report.tests.clone());
+    }
+
+    if report.outcome.passed() {
+        if !progress.completed_exercises.iter().any(|done| done == id) {
+            progress.completed_exercises.push(id.to_string());
+        }
+        progress.current_exercise = exercises::get_next_exercise(&progress.completed_exercises);
+    }
+
+    save_progress(progress);
+}
+
+pub fn check_current_exercise(progress: &mut Progress) -> Exit {
     if let Some(current) = progress.current_exercise.clone() {
-        // Clear screen for consistent presentation
-        print!("\x1B[2J\x1B[1;1H");
-        
-        // Title
-        println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-        println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-        println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
-        println!();
-        
-        println!("{}", "🧪 Testing Your Solution".bright_yellow().bold());
-        println!("   Exercise {}", current.bright_white());
-        println!();
-        println!("{}", "─────────────────────────────────────────────────".bright_black());
-        println!();
+        if !render::json() {
+            render::screen();
 
-        if exercises::check_exercise(&current) {
-            // Mark exercise as complete
-            if !progress.completed_exercises.contains(&current) {
-                progress.completed_exercises.push(current.clone());
+            println!("{}", "🧪 Testing Your Solution".bright_yellow().bold());
+            println!("   Exercise {}", current.bright_white());
+            println!();
+            render::separator();
+        }
+
+        let report = exercises::check_exercise(&current);
+        record_check(progress, &current, &report);
+
+        if render::json() {
+            render::print_json(&json::check(&current, &report, progress));
+            return report.outcome.into();
+        }
+
+        if report.outcome.passed() {
+            if exercises::find_exercise(&current).is_some_and(|e| e.is_placeholder()) {
+                println!("{}", format!("📝 Exercise {} is still a placeholder with no test suite, so it passes as written.",
+                    current).bright_black());
             }
-            
+
             // Get the next exercise
-            if let Some(next) = exercises::get_next_exercise(&progress.completed_exercises) {
-                progress.current_exercise = Some(next.clone());
-                save_progress(progress);
-                
-                println!();
-                println!("{}", "─────────────────────────────────────────────────".bright_black());
+            if let Some(next) = progress.current_exercise.clone() {
                 println!();
+                render::separator();
                 println!("{}", "🎉 Success!".bright_green().bold());
                 println!("   You've completed Exercise {}!", current.bright_white());
                 println!();
                 println!("   {} Exercise {}", "Next up:".bright_cyan(), next.bright_white().bold());
                 println!();
-                println!("   {} to generate the next exercise!", "cargo run -- next".bright_green().bold());
-                println!();
+                render::next_action("cargo run -- next", "to generate the next exercise!");
+
+                print_other_unlocked(&exercises::get_unlocked_exercises(&progress.completed_exercises), &next);
             } else {
-                progress.current_exercise = None;
-                save_progress(progress);
-                
-                println!();
-                println!("{}", "─────────────────────────────────────────────────".bright_black());
-                println!();
-                println!("{}", "🎉 CONGRATULATIONS!".bright_green().bold());
-                println!("   You've completed ALL the exercises!");
-                println!();
-                println!("   You've mastered the fundamentals of async Rust programming.");
-                println!("   Time to build something amazing with your new skills! 🚀");
                 println!();
+                render::separator();
+                print_congratulations();
             }
-        } else {
-            println!();
-            println!("{}", "─────────────────────────────────────────────────".bright_black());
+        } else if report.outcome != exercises::CheckOutcome::SetupFailed {
             println!();
+            render::separator();
             println!("{}", "💡 Keep Going!".bright_yellow().bold());
             println!("   Don't worry - async can be tricky at first!");
             println!("   Take another look at the error messages above.");
             println!("   Remember: the compiler is your friend! 🦀");
             println!();
+            let has_hints = exercises::find_exercise(&current).is_some_and(|e| !e.hints.is_empty());
+            if has_hints {
+                println!("   Still stuck? {} reveals a hint.", "cargo run -- hint".bright_cyan());
+                println!();
+            }
         }
+        report.outcome.into()
+    } else if render::json() {
+        render::print_json(&json::error("no_current_exercise", None, &[]));
+        Exit::NotFound
     } else {
         println!("No exercise loaded. Run `cargo run -- next` to get started.");
+        Exit::NotFound
     }
 }
 
+pub fn watch_exercises(progress: &mut Progress) -> Exit {
+    // Check whatever's there right away, after that only on saves
+    let mut check_now = true;
+
+    loop {
+        let Some(current) = progress.current_exercise.clone() else {
+            println!();
+            print_congratulations();
+            println!("   Nothing left to watch.");
+            println!();
+            return Exit::Success;
+        };
+        let Some(exercise) = exercises::find_exercise(&current) else {
+            eprintln!("{} {}", "❌ There's no exercise".bright_red(), current.bright_white());
+            return Exit::NotFound;
+        };
+
+        let filename = exercise.filename();
+        if !Path::new(&filename).exists() {
+            exercises::generate_exercise(&current, false);
+        }
+        if !Path::new(&filename).exists() {
+            eprintln!("{} {}", "❌ Couldn't create".bright_red(), filename);
+            return Exit::NotFound;
+        }
+
+        let watcher = match exercises::watcher::FileWatcher::new(Path::new(&filename)) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("{} {}: {}", "❌ Couldn't watch".bright_red(), filename, e);
+                return Exit::SetupFailed;
+            }
+        };
+
+        if !check_now {
+            println!("{}", format!("👀 Watching {} - save it to check, Ctrl+C to stop", filename).bright_black());
+        }
+
+        loop {
+            if !check_now && !watcher.wait_for_save() {
+                eprintln!("{} {}", "❌ Stopped receiving changes to".bright_red(), filename);
+                return Exit::SetupFailed;
+            }
+            check_now = false;
+
+            // Clear screen so every run starts at the top
+            render::clear();
+
+            let report = exercises::check_exercise(&current);
+            record_check(progress, &current, &report);
+            println!();
+            print_watch_status(&current, &report);
+
+            if report.outcome.passed() {
+                break;
+            }
+            println!("{}", format!("👀 Watching {} - save it to check again, Ctrl+C to stop", filename).bright_black());
+        }
+
+        // Passed - on to the next one, which gets checked once it's saved
+        if let Some(next) = progress.current_exercise.as_deref() {
+            if let Some(exercise) = exercises::find_exercise(next) {
+                exercises::generate_exercise(next, false);
+                println!("   {} Exercise {}: {}  {}", "➡️  Moving on to".bright_cyan(), next.bright_white().bold(),
+                    exercise.title, exercise.filename().bright_white().underline());
+            }
+        }
+    }
+}
+
+/// One line saying how the latest check in watch mode went
+fn print_watch_status(id: &str, report: &exercises::CheckReport) {
+    let time = chrono::Local::now().format("%H:%M:%S").to_string().bright_black();
+    let (passed, total) = exercises::results::tally(&report.tests, has_hidden_suite(id));
+
+    let status = match report.outcome {
+        exercises::CheckOutcome::Passed => format!("✅ {} passed", id).bright_green().bold(),
+        exercises::CheckOutcome::TestsFailed if total > 0 => {
+            format!("❌ {} - {}/{} tests passing", id, passed, total).bright_red().bold()
+        }
+        exercises::CheckOutcome::TestsFailed => format!("❌ {} - tests failing", id).bright_red().bold(),
+        exercises::CheckOutcome::BuildFailed => format!("❌ {} doesn't compile yet", id).bright_red().bold(),
+        exercises::CheckOutcome::Hung => format!("⏳ {} - a test hung", id).bright_yellow().bold(),
+        exercises::CheckOutcome::Missing => format!("❓ {} - no exercise file", id).bright_red().bold(),
+        exercises::CheckOutcome::SetupFailed => format!("❌ {} - couldn't set up the check", id).bright_red().bold(),
+    };
+    println!("{} {}", time, status);
+}
+
+pub fn restore_exercise(id: &str, backup_name: Option<&str>) -> Exit {
+    render::screen();
+
+    let Some(exercise) = exercises::find_exercise(id) else {
+        render::invalid_exercise(id);
+        return Exit::NotFound;
+    };
+
+    let filename = exercise.filename();
+    let backups = exercises::backup::list_backups(exercise);
+
+    let Some(backup_name) = backup_name else {
+        println!("{}", "🗄️  Backups".bright_yellow().bold());
+        println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+        println!();
+        if backups.is_empty() {
+            println!("   No backups yet - they're made whenever a changed file gets regenerated.");
+            println!();
+        } else {
+            for backup in &backups {
+                println!("   • {}", backup.name.bright_white());
+            }
+            println!();
+            render::separator();
+            render::next_action(&format!("cargo run -- restore {} <backup>", id), "to bring one back");
+        }
+        return Exit::Success;
+    };
+
+    let Some(backup) = exercises::backup::find_backup(exercise, backup_name) else {
+        println!("{}", "❌ Backup Not Found".bright_red().bold());
+        println!("   Exercise {} has no backup named {}", id.bright_white(), backup_name.bright_white());
+        println!();
+        println!("   {} to see the available backups", format!("cargo run -- restore {}", id).bright_cyan());
+        println!();
+        return Exit::NotFound;
+    };
+
+    let restored = fs::read_to_string(&backup.path)
+        .and_then(|restored| replace_exercise_file(exercise, &restored));
+    let safety_backup = match restored {
+        Ok(safety_backup) => safety_backup,
+        Err(e) => {
+            print_write_error(&filename, &e);
+            return Exit::WriteFailed;
+        }
+    };
+
+    println!("{}", "♻️  Backup Restored".bright_yellow().bold());
+    println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+    println!("   {} {}", "Restored".bright_blue(), backup.name.bright_white());
+    println!("   {} {}", "File".bright_blue(), filename.bright_white().underline());
+    if let Some(safety_backup) = safety_backup {
+        println!("   {} {}", "Previous version saved as".bright_blue(), safety_backup.name.bright_white());
+    }
+    println!();
+    render::separator();
+    render::next_action("cargo run -- check", "when you're ready to test your solution!");
+    Exit::Success
+}
+
+/// Put `contents` into the exercise file. Whatever different version is
+/// in there right now gets a backup of its own, so this can be undone
+fn replace_exercise_file(exercise: &exercises::Exercise, contents: &str) -> io::Result<Option<exercises::backup::Backup>> {
+    let filename = exercise.filename();
+    let safety_backup = match fs::read_to_string(&filename) {
+        Ok(current) if current != contents => Some(exercises::backup::create_backup(exercise)?),
+        _ => None,
+    };
+
+    if let Some(dir) = Path::new(&filename).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&filename, contents)?;
+    Ok(safety_backup)
+}
+
+fn print_write_error(filename: &str, error: &io::Error) {
+    println!("{}", "❌ Couldn't Restore".bright_red().bold());
+    println!("   {}: {}", filename.bright_white(), error);
+    println!("   Make sure {} and {} are writable - your file hasn't been changed.",
+        "exercises/".bright_white(), format!("{}/", exercises::backup::BACKUP_DIR).bright_white());
+    println!();
+}
+
+/// Snapshot the checked code so the attempt shows up in `history`
+fn record_attempt(id: &str, report: &exercises::CheckReport) {
+    // Nothing was built, so there's no attempt to speak of
+    let Some(code) = &report.code else {
+        return;
+    };
+
+    if let Some(exercise) = exercises::find_exercise(id) {
+        match exercises::history::record_attempt(exercise, report.outcome, code) {
+            Ok(_) if render::json() => {}
+            Ok(attempt) => println!("{}", format!("📸 Saved as attempt #{} - see `cargo run -- history {}`",
+                attempt.number, id).bright_black()),
+            Err(e) => eprintln!("{} {}", "⚠️  Couldn't save this attempt:".bright_yellow(), e),
+        }
+    }
+}
+
+/// Label for an attempt outcome
+fn outcome_label(outcome: exercises::CheckOutcome) -> ColoredString {
+    match outcome {
+        exercises::CheckOutcome::Passed => "✅ passed".bright_green(),
+        exercises::CheckOutcome::TestsFailed => "❌ tests failed".bright_red(),
+        exercises::CheckOutcome::BuildFailed => "🔧 didn't compile".bright_yellow(),
+        exercises::CheckOutcome::Hung => "⏳ hung".bright_yellow(),
+        exercises::CheckOutcome::Missing => "missing".bright_black(),
+        exercises::CheckOutcome::SetupFailed => "couldn't set up".bright_black(),
+    }
+}
+
+pub fn show_history(id: &str, diff: Option<&[u32]>, restore: Option<u32>) -> Exit {
+    render::screen();
+
+    let Some(exercise) = exercises::find_exercise(id) else {
+        render::invalid_exercise(id);
+        return Exit::NotFound;
+    };
+
+    let attempts = exercises::history::load_attempts(exercise);
+    let missing: Vec<u32> = diff.unwrap_or_default().iter().chain(restore.iter())
+        .copied()
+        .filter(|number| !attempts.iter().any(|a| a.number == *number))
+        .collect();
+
+    if !missing.is_empty() {
+        println!("{}", "❌ Attempt Not Found".bright_red().bold());
+        for number in missing {
+            println!("   Exercise {} has no attempt #{}", id.bright_white(), number);
+        }
+        println!();
+        println!("   {} to see the recorded attempts", format!("cargo run -- history {}", id).bright_cyan());
+        println!();
+        return Exit::NotFound;
+    }
+
+    if let Some(diff) = diff {
+        // A single attempt is compared with the one before it (or the
+        // template, for the very first attempt)
+        let (old_label, old, new_number) = match diff {
+            [old, new] => (format!("attempt #{}", old), exercises::history::read_snapshot(exercise, *old), *new),
+            [new] => match attempts.iter().rev().find(|a| a.number < *new) {
+                Some(previous) => (format!("attempt #{}", previous.number),
+                    exercises::history::read_snapshot(exercise, previous.number), *new),
+                None => ("template".to_string(), fs::read_to_string(&exercise.template).ok(), *new),
+            },
+            _ => unreachable!("clap limits --diff to one or two attempts"),
+        };
+        let new = exercises::history::read_snapshot(exercise, new_number);
+
+        println!("{}", "🔍 Attempt Diff".bright_yellow().bold());
+        println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+        println!();
+        exercises::diff::print_unified(&old.unwrap_or_default(), &new.unwrap_or_default(),
+            &old_label, &format!("attempt #{}", new_number));
+        println!();
+        return Exit::Success;
+    }
+
+    if let Some(number) = restore {
+        let filename = exercise.filename();
+        let Some(restored) = exercises::history::read_snapshot(exercise, number) else {
+            println!("{}", "❌ Attempt Not Found".bright_red().bold());
+            println!("   The snapshot of attempt #{} is gone from {}", number,
+                exercises::history::snapshot_path(exercise, number).display().to_string().bright_white());
+            println!();
+            return Exit::NotFound;
+        };
+
+        // Same safety net as regenerating: keep whatever is there now
+        let safety_backup = match replace_exercise_file(exercise, &restored) {
+            Ok(safety_backup) => safety_backup,
+            Err(e) => {
+                print_write_error(&filename, &e);
+                return Exit::WriteFailed;
+            }
+        };
+
+        println!("{}", "♻️  Attempt Restored".bright_yellow().bold());
+        println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+        println!("   {} attempt #{}", "Restored".bright_blue(), number);
+        println!("   {} {}", "File".bright_blue(), filename.bright_white().underline());
+        if let Some(safety_backup) = safety_backup {
+            println!("   {} {}", "Previous version saved as backup".bright_blue(), safety_backup.name.bright_white());
+        }
+        println!();
+        return Exit::Success;
+    }
+
+    println!("{}", "🕰️  Attempt History".bright_yellow().bold());
+    println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+    println!();
+
+    if attempts.is_empty() {
+        println!("   No attempts yet - every {} is recorded here.", "check".bright_cyan());
+        println!();
+        return Exit::Success;
+    }
+
+    for attempt in &attempts {
+        println!("   {}  {}  {}", format!("#{:<3}", attempt.number).bright_white(),
+            attempt.timestamp.bright_black(), outcome_label(attempt.outcome));
+    }
+
+    println!();
+    render::separator();
+    println!("   {} to see what changed in an attempt",
+        format!("cargo run -- history {} --diff <n> [<m>]", id).bright_green().bold());
+    render::next_action(&format!("cargo run -- history {} --restore <n>", id), "to go back to an attempt");
+    Exit::Success
+}
+
+/// What each level of hint is, vaguest first
+const HINT_LEVELS: [&str; 3] = ["💭 Concept", "🔧 API", "🧩 Almost there"];
+
+pub fn show_hint(id: Option<&str>, progress: &mut Progress) -> Exit {
+    render::screen();
+
+    let Some(id) = id.map(str::to_string).or_else(|| progress.current_exercise.clone()) else {
+        println!("No exercise loaded. Run `cargo run -- next` to get started.");
+        return Exit::NotFound;
+    };
+
+    let Some(exercise) = exercises::find_exercise(&id) else {
+        render::invalid_exercise(&id);
+        return Exit::NotFound;
+    };
+
+    if exercise.hints.is_empty() {
+        println!("{}", "❌ No Hints".bright_red().bold());
+        if exercise.is_placeholder() {
+            println!("   Exercise {} is still a placeholder - there's nothing to solve yet,", id.bright_white());
+            println!("   so there's nothing to hint at either. {} moves you on.",
+                "cargo run -- check".bright_cyan());
+        } else {
+            println!("   Exercise {} doesn't have any hints", id.bright_white());
+        }
+        println!();
+        return Exit::NotFound;
+    }
+
+    // Every call reveals one more, and shows the ones revealed before
+    let used = progress.hints_used.entry(id.clone()).or_default();
+    *used = (*used + 1).min(exercise.hints.len());
+    let used = *used;
+    save_progress(progress);
+
+    println!("{}", "💡 Hints".bright_yellow().bold());
+    println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+    println!();
+
+    for (level, hint) in exercise.hints.iter().take(used).enumerate() {
+        let label = HINT_LEVELS.get(level).map_or_else(|| format!("Hint {}", level + 1), |label| label.to_string());
+        println!("   {}", label.bright_cyan().bold());
+        for line in hint.trim_end().lines() {
+            println!("   {}", line);
+        }
+        println!();
+    }
+
+    render::separator();
+    if used < exercise.hints.len() {
+        println!("   {} of {} hints revealed - {} again for the next one",
+            used, exercise.hints.len(), format!("cargo run -- hint {}", id).bright_green().bold());
+    } else if exercise.solution.is_some() {
+        println!("   That's every hint. Still stuck? {} shows the whole thing.",
+            format!("cargo run -- solution {} --reveal", id).bright_green().bold());
+    } else {
+        println!("   That's every hint.");
+    }
+    println!();
+    Exit::Success
+}
+
+pub fn show_solution(id: &str, progress: &Progress, reveal: bool) -> Exit {
+    render::screen();
+
+    let Some(exercise) = exercises::find_exercise(id) else {
+        render::invalid_exercise(id);
+        return Exit::NotFound;
+    };
+
+    let Some(reference) = exercise.solution.as_ref().and_then(|path| fs::read_to_string(path).ok()) else {
+        println!("{}", "❌ No Reference Solution".bright_red().bold());
+        println!("   Exercise {} doesn't come with one", id.bright_white());
+        println!();
+        return Exit::NotFound;
+    };
+
+    if !progress.completed_exercises.contains(&exercise.id) && !reveal {
+        println!("{}", "🔒 Solution Locked".bright_yellow().bold());
+        println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+        println!();
+        println!("   The reference solution unlocks once the exercise passes {}.", "check".bright_cyan());
+        println!("   Stuck for good? {} shows it anyway.",
+            format!("cargo run -- solution {} --reveal", id).bright_cyan());
+        println!();
+        return Exit::Locked;
+    }
+
+    println!("{}", "📖 Reference Solution".bright_yellow().bold());
+    println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+    println!();
+
+    let filename = exercise.filename();
+    match fs::read_to_string(&filename) {
+        Ok(yours) => exercises::diff::print_side_by_side(&yours, &reference, &filename, "reference solution"),
+        Err(_) => {
+            for line in reference.lines() {
+                println!("   {}", line);
+            }
+        }
+    }
+    println!();
+    Exit::Success
+}
+
+pub fn show_timeline(id: &str) -> Exit {
+    render::screen();
+
+    let Some(exercise) = exercises::find_exercise(id) else {
+        render::invalid_exercise(id);
+        return Exit::NotFound;
+    };
+
+    if exercise.timeline.is_empty() {
+        println!("{}", "❌ No Timeline".bright_red().bold());
+        println!("   Exercise {} has nothing to chart", id.bright_white());
+        println!();
+        return Exit::NotFound;
+    }
+
+    if !Path::new(&exercise.filename()).exists() {
+        println!("{}", "❌ Exercise Not Started".bright_red().bold());
+        println!("   {} to generate the exercise file first",
+            format!("cargo run -- run {}", id).bright_cyan());
+        println!();
+        return Exit::NotFound;
+    }
+
+    println!("{}", "📊 Timeline".bright_yellow().bold());
+    println!("   Exercise {}: {}", id.bright_white(), exercise.title);
+    println!("{}", "   Running your code on a virtual clock...".bright_black());
+    println!();
+
+    let exit = match exercises::timeline::record(exercise) {
+        Ok(variants) => {
+            exercises::timeline::print_chart(&variants);
+            Exit::Success
+        }
+        Err(exercises::timeline::Failure::Build) => {
+            println!("{}", "❌ Your code doesn't compile yet".bright_red());
+            println!("   {} to see what's wrong", "cargo run -- check".bright_cyan());
+            Exit::BuildFailed
+        }
+        Err(exercises::timeline::Failure::BuildTimeout(limit)) => {
+            println!("{}", format!("⏳ The build took longer than {}s, so I stopped it.", limit.as_secs()).bright_red());
+            println!("   If it's still compiling dependencies, raise {} in .async-book.toml.",
+                "build_timeout_secs".bright_white());
+            Exit::BuildFailed
+        }
+        Err(exercises::timeline::Failure::Setup(e)) => {
+            exercises::print_setup_error(&e);
+            Exit::SetupFailed
+        }
+    };
+    println!();
+    exit
+}
+
 pub fn reset_progress() {
     // Remove progress file
     if Path::new(PROGRESS_FILE).exists() {
@@ -295,40 +956,35 @@ pub fn reset_progress() {
     // Clean up generated exercise files
     exercises::cleanup_all();
 
-    // Clear screen for a fresh start
-    print!("\x1B[2J\x1B[1;1H");
-    
-    // Title
-    println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
-    println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
-    println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
-    println!();
+    render::screen();
     
     // Reset status
     println!("{}", "✨ Progress Reset".bright_yellow().bold());
     println!("   All data cleared:");
     println!("   ✓ Progress file removed");
     println!("   ✓ Exercise files deleted");
+    println!("   ✓ Attempt history cleared");
     println!("   ✓ Test artifacts cleaned");
+    if Path::new(exercises::backup::BACKUP_DIR).exists() {
+        println!("   Your backups are kept in {} - {} still brings them back",
+            format!("{}/", exercises::backup::BACKUP_DIR).bright_white(),
+            "cargo run -- restore <id>".bright_cyan());
+    }
     println!();
     
-    // Current status
+    // Current status - everything starts over from the defaults
+    let progress = Progress::default();
     println!("{}", "📊 Your Progress".bright_yellow().bold());
-    print!("   ");
-    let bar_width = 40;
-    print!("[");
-    for _ in 0..bar_width {
-        print!("{}", "░".bright_black());
-    }
-    println!("] 0/10 (0%)");
-    println!("   {} Exercise 1.1: Basic Async/Await", "Ready for".bright_cyan());
-    println!("   {} {}", "Will create".bright_blue(), "exercises/ch01_ex01.rs".bright_white().underline());
+    render::progress_bar(progress.tally());
+    let first = progress.current_exercise.as_deref().and_then(exercises::find_exercise);
+    if let Some(exercise) = first {
+        println!("   {} Exercise {}: {}", "Ready for".bright_cyan(), exercise.id, exercise.title);
+        println!("   {} {}", "Will create".bright_blue(), exercise.filename().bright_white().underline());
+    }
     println!();
     
-    println!("{}", "─────────────────────────────────────────────────".bright_black());
-    println!();
+    render::separator();
     
     // Next action
-    println!("   {} to begin your async journey!", "cargo run -- next".bright_green().bold());
-    println!();
+    render::next_action("cargo run -- next", "to begin your async journey!");
 }
\ No newline at end of file
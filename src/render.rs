@@ -0,0 +1,191 @@
+//! The pieces every screen is built from
+//!
+//! Screens start with [`screen`] (clear the terminal, draw the title),
+//! split their sections with [`separator`] and end on a [`next_action`].
+//! How those look depends on the theme, picked with `theme` in
+//! `.async-book.toml` or `--theme`:
+//!
+//! - `colorful` - the default: box-drawing title, colored progress bar
+//! - `plain` - no colors, and an ASCII title, separators and progress bar,
+//!   for logs. Messages keep their emoji and symbols (✓, 🔒, ...)
+//! - `compact` - no screen clearing and no boxes, as few lines as possible
+//!
+//! With `--format json` there are no screens at all: every piece here draws
+//! nothing, and commands print a JSON document instead.
+//!
+//! The screen is only cleared when stdout is a terminal, and never with
+//! `--no-clear`. Colors follow `colored`, which leaves them out when stdout
+//! isn't a terminal or `NO_COLOR` is set.
+
+use colored::*;
+use serde::Deserialize;
+use std::io::{self, IsTerminal, Write};
+use std::sync::OnceLock;
+
+use crate::config;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    Colorful,
+    Plain,
+    Compact,
+}
+
+/// What commands print
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Screens for people
+    #[default]
+    Text,
+    /// One JSON document, for scripts
+    Json,
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+static FORMAT: OnceLock<Format> = OnceLock::new();
+static CLEAR: OnceLock<bool> = OnceLock::new();
+
+/// Settle on a theme and format for the rest of the run. The theme is
+/// `chosen` if given (the command line), otherwise the one in the config
+pub fn init(chosen: Option<Theme>, format: Format, no_clear: bool) {
+    let theme = *THEME.get_or_init(|| chosen.unwrap_or_else(|| config::load_config().theme));
+    let format = *FORMAT.get_or_init(|| format);
+    CLEAR.get_or_init(|| !no_clear && io::stdout().is_terminal());
+    if theme == Theme::Plain || format == Format::Json {
+        colored::control::set_override(false);
+    }
+}
+
+/// The theme in use - the config's, if [`init`] was never called
+pub fn theme() -> Theme {
+    *THEME.get_or_init(|| config::load_config().theme)
+}
+
+/// Whether commands print JSON rather than screens
+pub fn json() -> bool {
+    FORMAT.get().is_some_and(|format| *format == Format::Json)
+}
+
+/// Print a command's result as JSON
+pub fn print_json(value: &impl serde::Serialize) {
+    println!("{}", serde_json::to_string_pretty(value).expect("Failed to serialize output"));
+}
+
+/// Exercises done, out of how many
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tally {
+    pub completed: usize,
+    /// Completed exercises plus the passed share of the others
+    pub credit: f32,
+    pub total: usize,
+}
+
+/// Start a new screen: clear the terminal and draw the title
+pub fn screen() {
+    if json() {
+        return;
+    }
+
+    match theme() {
+        Theme::Colorful => {
+            clear();
+            println!("{}", "╔════════════════════════════════════════════════╗".bright_cyan());
+            println!("{} {} {}", "║".bright_cyan(), "      🦀 Rust Async Book Tutorial 📚           ".bright_white().bold(), "║".bright_cyan());
+            println!("{}", "╚════════════════════════════════════════════════╝".bright_cyan());
+        }
+        Theme::Plain => {
+            clear();
+            println!("+------------------------------------------------+");
+            println!("|          Rust Async Book Tutorial              |");
+            println!("+------------------------------------------------+");
+        }
+        Theme::Compact => println!("{}", "🦀 Rust Async Book Tutorial".bright_white().bold()),
+    }
+    println!();
+}
+
+/// Clear the terminal, unless the theme (or `--no-clear`) keeps what's
+/// already there - or there's no terminal to clear
+pub fn clear() {
+    let clear = *CLEAR.get_or_init(|| io::stdout().is_terminal());
+    if clear && theme() != Theme::Compact && !json() {
+        print!("\x1B[2J\x1B[1;1H");
+        io::stdout().flush().ok();
+    }
+}
+
+/// Line between the sections of a screen
+pub fn separator() {
+    if json() {
+        return;
+    }
+
+    match theme() {
+        Theme::Colorful => println!("{}", "─────────────────────────────────────────────────".bright_black()),
+        Theme::Plain => println!("-------------------------------------------------"),
+        Theme::Compact => return,
+    }
+    println!();
+}
+
+/// The progress bar, with partially passed exercises counted in
+pub fn progress_bar(tally: Tally) {
+    if json() {
+        return;
+    }
+
+    let (bar_width, full_cell, partial_cell, empty_cell) = match theme() {
+        Theme::Colorful => (40, "█", "▓", "░"),
+        Theme::Plain => (40, "#", "+", "."),
+        Theme::Compact => (20, "█", "▓", "░"),
+    };
+
+    let total = tally.total.max(1);
+    let percentage = (tally.credit / total as f32 * 100.0) as u32;
+    let full = bar_width * tally.completed / total;
+    let filled = ((bar_width as f32 * tally.credit / total as f32).round() as usize).max(full);
+
+    let bar: String = (0..bar_width)
+        .map(|i| {
+            if i < full {
+                full_cell.bright_green().to_string()
+            } else if i < filled {
+                partial_cell.bright_yellow().to_string()
+            } else {
+                empty_cell.bright_black().to_string()
+            }
+        })
+        .collect();
+
+    print!("   [{}] {}/{} ({}%)", bar, tally.completed, tally.total, percentage);
+    if tally.credit > tally.completed as f32 {
+        print!(" {}", "incl. partial credit".bright_yellow());
+    }
+    println!();
+}
+
+/// The screen for an exercise id that doesn't exist
+pub fn invalid_exercise(id: &str) {
+    if json() {
+        return;
+    }
+
+    println!("{}", "❌ Invalid Exercise".bright_red().bold());
+    println!("   Exercise {} not found", id.bright_white());
+    println!();
+    next_action("cargo run -- list", "to see all available exercises");
+}
+
+/// What to run next, e.g. "cargo run -- check to test your solution"
+pub fn next_action(command: &str, what: &str) {
+    if json() {
+        return;
+    }
+
+    println!("   {} {}", command.bright_green().bold(), what);
+    if theme() != Theme::Compact {
+        println!();
+    }
+}